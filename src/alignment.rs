@@ -0,0 +1,184 @@
+use crate::errors::BytesParserError;
+use crate::parser::BytesParser;
+
+/// Control what position the padding is computed from, when aligning the cursor.
+///
+/// This only makes a difference for a [`BytesParser`] created with [`BytesParser::from_slice`]
+/// (or [`BytesParser::from_end`]), as formats differ on whether alignment is relative to the start
+/// of the enclosing structure, or to the start of the whole file.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum AlignmentBase {
+    /// Align relative to the start of the bytes array of the [`BytesParser`] (default).
+    ///
+    /// See [`BytesParser::position`].
+    Relative,
+
+    /// Align relative to the start of the original bytes array, that any sub-parser was cut from.
+    ///
+    /// See [`BytesParser::absolute_position`].
+    Absolute,
+}
+
+impl Default for AlignmentBase {
+    /// Default value for [AlignmentBase] is [AlignmentBase::Relative].
+    fn default() -> Self {
+        AlignmentBase::Relative
+    }
+}
+
+impl<'a> BytesParser<'a> {
+    /// Returns the amount of padding bytes between the cursor and the next multiple of `alignment`.
+    ///
+    /// The position of the cursor is determined by [`Self::alignment_base`].
+    /// An `alignment` of `0` or `1` never requires padding.
+    ///
+    /// # Arguments
+    ///
+    /// * `alignment` - The boundary to align to, in bytes (e.g. `4` for 32 bits alignment).
+    pub fn padding_to(&self, alignment: usize) -> usize {
+        if alignment <= 1 {
+            return 0;
+        }
+
+        let position = match self.alignment_base() {
+            AlignmentBase::Relative => self.position(),
+            AlignmentBase::Absolute => self.absolute_position(),
+        };
+
+        (alignment - position % alignment) % alignment
+    }
+
+    /// Move internal cursor forward to the next multiple of `alignment`, skipping the padding bytes.
+    ///
+    /// It produces an error if the new cursor position would fall out-of-bound of the
+    /// internal bytes array. See [`Self::padding_to`].
+    ///
+    /// # Arguments
+    ///
+    /// * `alignment` - The boundary to align to, in bytes (e.g. `4` for 32 bits alignment).
+    pub fn align_to(&mut self, alignment: usize) -> Result<(), BytesParserError> {
+        self.skip_padding(self.padding_to(alignment))
+    }
+
+    /// Move internal cursor forward by `amount` of padding bytes.
+    ///
+    /// Differently from [`Self::move_forward`], this accepts moving the cursor at the very end
+    /// (i.e. [`Self::is_at_end`] will return [`true`]), as padding often closes a structure.
+    /// It produces an error if the new cursor position would fall out-of-bound of the
+    /// internal bytes array.
+    ///
+    /// # Arguments
+    ///
+    /// * `amount` - Amount of padding bytes to skip.
+    pub fn skip_padding(&mut self, amount: usize) -> Result<(), BytesParserError> {
+        if self.parseable() < amount {
+            let new_position = self.position().checked_add(amount).and_then(|end| isize::try_from(end).ok());
+            return Err(BytesParserError::CursorOutOfBoundError(
+                new_position.unwrap_or(isize::MAX),
+                self.length(),
+                self.position(),
+            ));
+        }
+
+        self.parse_slice(amount)?;
+
+        Ok(())
+    }
+
+    /// Move internal cursor forward by `amount` of padding bytes, verifying they are all zero.
+    ///
+    /// Like [`Self::skip_padding`], but it also produces an error if any of the padding bytes
+    /// is not zero. In case of error, the cursor is not moved.
+    ///
+    /// # Arguments
+    ///
+    /// * `amount` - Amount of padding bytes to skip.
+    pub fn expect_zero_padding(&mut self, amount: usize) -> Result<(), BytesParserError> {
+        if self.parseable() < amount {
+            return self.skip_padding(amount);
+        }
+
+        let mut p = *self;
+        let padding = p.parse_slice(amount)?;
+        if let Some(i) = padding.iter().position(|b| *b != 0) {
+            return Err(BytesParserError::NonZeroPaddingError(padding[i], self.position() + i));
+        }
+
+        *self = p;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{AlignmentBase, BytesParser, BytesParserError};
+
+    #[test]
+    fn align_to() {
+        let input: &[u8] = &[
+            0x01, 0x00, 0x00, 0x00, //< u8 + padding to 4 bytes
+            0x00, 0x02, 0x00, 0x00, //< u16 + padding to 4 bytes
+            0x03, //< u8
+        ];
+
+        let mut p = BytesParser::from(input);
+
+        assert_eq!(p.padding_to(4), 0);
+        assert_eq!(p.parse_u8().unwrap(), 1);
+        assert_eq!(p.padding_to(4), 3);
+        assert!(p.align_to(4).is_ok());
+        assert_eq!(p.parse_u16().unwrap(), 2);
+        assert!(p.expect_zero_padding(p.padding_to(4)).is_ok());
+        assert_eq!(p.parse_u8().unwrap(), 3);
+
+        assert_eq!(p.padding_to(2), 1);
+        assert_eq!(p.align_to(2).unwrap_err(), BytesParserError::CursorOutOfBoundError(10, 9, 9));
+        assert_eq!(p.padding_to(0), 0);
+        assert_eq!(p.padding_to(1), 0);
+    }
+
+    #[test]
+    fn align_to_relative_or_absolute() {
+        let input: &[u8] = &[0xFF, 0xFF, 0xFF, 0x01, 0x00, 0x00, 0x00, 0x00];
+
+        let mut p = BytesParser::from(input);
+        assert!(p.move_forward(3).is_ok());
+
+        let mut ps = p.from_slice(5).unwrap();
+        assert_eq!(ps.parse_u8().unwrap(), 1);
+        assert_eq!(ps.padding_to(4), 3);
+
+        ps.set_alignment_base(AlignmentBase::Absolute);
+        assert_eq!(ps.padding_to(4), 0);
+
+        ps.reset();
+        assert_eq!(ps.padding_to(4), 1);
+        assert!(ps.align_to(4).is_ok());
+        assert_eq!(ps.position(), 1);
+        assert_eq!(ps.absolute_position(), 4);
+    }
+
+    #[test]
+    fn skip_padding() {
+        let input: &[u8] = &[0x01, 0x00, 0xAA, 0x00];
+
+        let mut p = BytesParser::from(input);
+
+        assert!(p.skip_padding(1).is_ok());
+        assert_eq!(p.expect_zero_padding(3).unwrap_err(), BytesParserError::NonZeroPaddingError(0xAA, 2));
+        assert_eq!(p.position(), 1);
+        assert!(p.skip_padding(3).is_ok());
+        assert!(p.is_at_end());
+        assert!(p.skip_padding(0).is_ok());
+        assert_eq!(p.skip_padding(1).unwrap_err(), BytesParserError::CursorOutOfBoundError(5, 4, 4));
+
+        p.reset();
+        assert_eq!(p.skip_padding(usize::MAX).unwrap_err(), BytesParserError::CursorOutOfBoundError(isize::MAX, 4, 0));
+        assert!(p.move_forward(1).is_ok());
+        assert_eq!(
+            p.expect_zero_padding(usize::MAX).unwrap_err(),
+            BytesParserError::CursorOutOfBoundError(isize::MAX, 4, 1)
+        );
+        assert_eq!(p.position(), 1);
+    }
+}
@@ -0,0 +1,128 @@
+use crate::errors::BytesParserError;
+use crate::parser::BytesParser;
+use crate::scalar::private::Sealed;
+use crate::scalar::Scalar;
+
+macro_rules! build_parse_array_fn {
+    ($fn_name:ident, $fn_name_into:ident, $parsed_type:ty) => {
+        #[doc = "Parse `n` contiguous `"]
+        #[doc=stringify!($parsed_type)]
+        #[doc = "` into a [`Vec`] and update the internal cursor accordingly.\n\n"]
+        #[doc = "It produces an error if [`BytesParser::parseable`] returns an amount inferior to"]
+        #[doc = "the amount of bytes occupied by `n` `"]
+        #[doc=stringify!($parsed_type)]
+        #[doc = "`."]
+        pub fn $fn_name(&mut self, n: usize) -> Result<Vec<$parsed_type>, BytesParserError> {
+            let bytes = self.parse_scalars_slice::<$parsed_type>(n)?;
+
+            let mut values = vec![<$parsed_type>::default(); n];
+            <$parsed_type>::from_endian_bytes_into(bytes, self.endian(), &mut values);
+
+            Ok(values)
+        }
+
+        #[doc = "Parse as many contiguous `"]
+        #[doc=stringify!($parsed_type)]
+        #[doc = "` as fit in `values`, and update the internal cursor accordingly.\n\n"]
+        #[doc = "Like [`BytesParser::"]
+        #[doc=stringify!($fn_name)]
+        #[doc = "`], but parses into a caller-supplied slice, avoiding any allocation."]
+        pub fn $fn_name_into(&mut self, values: &mut [$parsed_type]) -> Result<(), BytesParserError> {
+            let bytes = self.parse_scalars_slice::<$parsed_type>(values.len())?;
+
+            <$parsed_type>::from_endian_bytes_into(bytes, self.endian(), values);
+
+            Ok(())
+        }
+    };
+}
+
+impl<'a> BytesParser<'a> {
+    build_parse_array_fn!(parse_i16_array, parse_i16_array_into, i16);
+    build_parse_array_fn!(parse_u16_array, parse_u16_array_into, u16);
+
+    build_parse_array_fn!(parse_i32_array, parse_i32_array_into, i32);
+    build_parse_array_fn!(parse_u32_array, parse_u32_array_into, u32);
+
+    build_parse_array_fn!(parse_i64_array, parse_i64_array_into, i64);
+    build_parse_array_fn!(parse_u64_array, parse_u64_array_into, u64);
+
+    build_parse_array_fn!(parse_i128_array, parse_i128_array_into, i128);
+    build_parse_array_fn!(parse_u128_array, parse_u128_array_into, u128);
+
+    build_parse_array_fn!(parse_f32_array, parse_f32_array_into, f32);
+    build_parse_array_fn!(parse_f64_array, parse_f64_array_into, f64);
+
+    build_parse_array_fn!(parse_isize_array, parse_isize_array_into, isize);
+    build_parse_array_fn!(parse_usize_array, parse_usize_array_into, usize);
+
+    /// Cut the slice of bytes occupied by `n` contiguous scalars of type `T`,
+    /// and update the internal cursor accordingly.
+    pub(crate) fn parse_scalars_slice<T: Scalar>(&mut self, n: usize) -> Result<&'a [u8], BytesParserError> {
+        match n.checked_mul(T::SIZE) {
+            Some(size) if size <= self.parseable() => self.parse_slice(size),
+            _ => Err(BytesParserError::NotEnoughBytesForTypeError(format!("[{}; {n}]", T::NAME))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{BytesParser, BytesParserError, ParsingEndian};
+
+    #[test]
+    fn parse_scalar_arrays() {
+        let input: &[u8] = &[
+            0x12, 0x34, 0x56, 0x78, //< 2 x u16, or 1 x u32
+            0x3F, 0x80, 0x00, 0x00, 0xBF, 0x80, 0x00, 0x00, //< 2 x f32
+        ];
+
+        let mut p = BytesParser::from(input);
+
+        assert_eq!(p.parse_u16_array(2).unwrap(), vec![0x1234, 0x5678]);
+        assert_eq!(p.parse_f32_array(2).unwrap(), vec![1.0, -1.0]);
+        assert!(p.is_at_end());
+
+        p.reset();
+        p.set_endian(ParsingEndian::LE);
+        assert_eq!(p.parse_u16_array(2).unwrap(), vec![0x3412, 0x7856]);
+
+        p.reset();
+        p.set_endian(ParsingEndian::PDP);
+        assert_eq!(p.parse_u32_array(1).unwrap(), vec![0x34127856]);
+
+        p.reset();
+        p.set_endian(ParsingEndian::Native);
+        assert_eq!(p.parse_i32_array(1).unwrap(), vec![i32::from_ne_bytes([0x12, 0x34, 0x56, 0x78])]);
+        assert!(p.parse_i32_array(0).unwrap().is_empty());
+    }
+
+    #[test]
+    fn parse_scalar_arrays_into() {
+        let input: &[u8] = &[0x00, 0x01, 0x00, 0x02, 0x00, 0x03];
+
+        let mut p = BytesParser::from(input);
+
+        let mut values = [0u16; 3];
+        assert!(p.parse_u16_array_into(&mut values).is_ok());
+        assert_eq!(values, [1, 2, 3]);
+        assert!(p.is_at_end());
+    }
+
+    #[test]
+    fn try_parsing_scalar_arrays_out_of_bound() {
+        let input: &[u8] = &[0x00, 0x01, 0x00, 0x02, 0x00, 0x03];
+
+        let mut p = BytesParser::from(input);
+
+        assert_eq!(
+            p.parse_u32_array(2).unwrap_err(),
+            BytesParserError::NotEnoughBytesForTypeError("[u32; 2]".to_string())
+        );
+        assert_eq!(
+            p.parse_u64_array(usize::MAX).unwrap_err(),
+            BytesParserError::NotEnoughBytesForTypeError(format!("[u64; {}]", usize::MAX))
+        );
+        assert!(p.is_at_start());
+    }
+}
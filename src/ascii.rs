@@ -0,0 +1,285 @@
+use crate::errors::BytesParserError;
+use crate::parser::BytesParser;
+
+use memchr::memchr;
+use std::any;
+use std::convert::TryFrom;
+
+/// Control which padding is tolerated around ASCII-encoded numbers, when parsing them.
+///
+/// See [`BytesParser::parse_ascii_integer`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum AsciiPadding {
+    /// No padding is tolerated: the field must be made exclusively of the number.
+    None,
+
+    /// Leading and trailing spaces (`0x20`) and NULs (`0x00`) are tolerated (default).
+    ///
+    /// This is what fixed-width fields usually do (e.g. TAR and ar headers).
+    SpacesOrNuls,
+}
+
+impl Default for AsciiPadding {
+    /// Default value for [AsciiPadding] is [AsciiPadding::SpacesOrNuls].
+    fn default() -> Self {
+        AsciiPadding::SpacesOrNuls
+    }
+}
+
+impl<'a> BytesParser<'a> {
+    /// Parse an ASCII-encoded decimal number, of fixed width `len`, into the integer type `T`,
+    /// and update the internal cursor accordingly.
+    ///
+    /// See [`Self::parse_ascii_integer`]: this uses radix `10` and [`AsciiPadding::SpacesOrNuls`].
+    ///
+    /// # Arguments
+    ///
+    /// * `len` - Width of the field, in bytes.
+    pub fn parse_ascii_decimal<T>(&mut self, len: usize) -> Result<T, BytesParserError>
+    where
+        T: TryFrom<u128> + TryFrom<i128>,
+    {
+        self.parse_ascii_integer(len, 10, AsciiPadding::SpacesOrNuls)
+    }
+
+    /// Parse an ASCII-encoded hexadecimal number, of fixed width `len`, into the integer type `T`,
+    /// and update the internal cursor accordingly.
+    ///
+    /// See [`Self::parse_ascii_integer`]: this uses radix `16` and [`AsciiPadding::SpacesOrNuls`].
+    ///
+    /// # Arguments
+    ///
+    /// * `len` - Width of the field, in bytes.
+    pub fn parse_ascii_hex<T>(&mut self, len: usize) -> Result<T, BytesParserError>
+    where
+        T: TryFrom<u128> + TryFrom<i128>,
+    {
+        self.parse_ascii_integer(len, 16, AsciiPadding::SpacesOrNuls)
+    }
+
+    /// Parse an ASCII-encoded octal number, of fixed width `len`, into the integer type `T`,
+    /// and update the internal cursor accordingly.
+    ///
+    /// See [`Self::parse_ascii_integer`]: this uses radix `8` and [`AsciiPadding::SpacesOrNuls`].
+    ///
+    /// # Arguments
+    ///
+    /// * `len` - Width of the field, in bytes.
+    pub fn parse_ascii_octal<T>(&mut self, len: usize) -> Result<T, BytesParserError>
+    where
+        T: TryFrom<u128> + TryFrom<i128>,
+    {
+        self.parse_ascii_integer(len, 8, AsciiPadding::SpacesOrNuls)
+    }
+
+    /// Parse an ASCII-encoded number, of fixed width `len` and given `radix`, into the integer
+    /// type `T`, and update the internal cursor accordingly.
+    ///
+    /// The number is made of an optional sign (`+` or `-`) followed by digits (case-insensitive,
+    /// for radix greater than `10`), surrounded by the tolerated `padding`.
+    /// It produces an error if `radix` is not in the range `2..=36`, if the field contains anything else,
+    /// or if the number does not fit in `T`. In case of error, the cursor is not moved.
+    ///
+    /// For delimiter-terminated numbers, see [`Self::parse_ascii_integer_until`].
+    ///
+    /// # Arguments
+    ///
+    /// * `len` - Width of the field, in bytes.
+    /// * `radix` - Radix of the number, in the range `2..=36`.
+    /// * `padding` - The [AsciiPadding] tolerated around the number.
+    pub fn parse_ascii_integer<T>(
+        &mut self,
+        len: usize,
+        radix: u32,
+        padding: AsciiPadding,
+    ) -> Result<T, BytesParserError>
+    where
+        T: TryFrom<u128> + TryFrom<i128>,
+    {
+        check_radix(radix)?;
+
+        let start = self.position();
+        let mut p = *self;
+        let field = p.parse_slice(len)?;
+
+        let is_padding = |b: &u8| padding == AsciiPadding::SpacesOrNuls && (*b == b' ' || *b == 0);
+        let digits_start = field.iter().position(|b| !is_padding(b)).unwrap_or(len);
+        let digits_end = field.iter().rposition(|b| !is_padding(b)).map_or(digits_start, |i| i + 1);
+
+        let (negative, digits_start) = match field.get(digits_start) {
+            Some(b'-') => (true, digits_start + 1),
+            Some(b'+') => (false, digits_start + 1),
+            _ => (false, digits_start),
+        };
+        if digits_start >= digits_end {
+            return Err(BytesParserError::InvalidAsciiNumberError(start + digits_start));
+        }
+
+        let overflow = || BytesParserError::AsciiNumberOverflowError(any::type_name::<T>().to_string(), start);
+        let mut magnitude: u128 = 0;
+        for (i, b) in field[digits_start..digits_end].iter().enumerate() {
+            let digit = (*b as char)
+                .to_digit(radix)
+                .ok_or(BytesParserError::InvalidAsciiNumberError(start + digits_start + i))?;
+            magnitude =
+                magnitude.checked_mul(radix as u128).and_then(|m| m.checked_add(digit as u128)).ok_or_else(overflow)?;
+        }
+
+        let value = if negative {
+            let signed = 0i128.checked_sub_unsigned(magnitude).ok_or_else(overflow)?;
+            T::try_from(signed).map_err(|_| overflow())?
+        } else {
+            T::try_from(magnitude).map_err(|_| overflow())?
+        };
+
+        *self = p;
+        Ok(value)
+    }
+
+    /// Parse an ASCII-encoded number, terminated by `delimiter` and of given `radix`, into the integer
+    /// type `T`, and update the internal cursor accordingly.
+    ///
+    /// The field goes from [`Self::position`] up to the first occurrence of `delimiter`, and it's parsed
+    /// like in [`Self::parse_ascii_integer`]: the cursor is then moved past the `delimiter`.
+    /// It produces an error if `radix` is not in the range `2..=36`, if `delimiter` is not found,
+    /// or if the field is not a valid number.
+    /// In case of error, the cursor is not moved.
+    ///
+    /// # Arguments
+    ///
+    /// * `delimiter` - The byte that terminates the field (e.g. `;` or `\r` for HTTP chunk sizes).
+    /// * `radix` - Radix of the number, in the range `2..=36`.
+    /// * `padding` - The [AsciiPadding] tolerated around the number.
+    pub fn parse_ascii_integer_until<T>(
+        &mut self,
+        delimiter: u8,
+        radix: u32,
+        padding: AsciiPadding,
+    ) -> Result<T, BytesParserError>
+    where
+        T: TryFrom<u128> + TryFrom<i128>,
+    {
+        check_radix(radix)?;
+
+        let mut p = *self;
+        let len =
+            memchr(delimiter, p.remaining()).ok_or_else(|| BytesParserError::PatternNotFoundError(vec![delimiter]))?;
+        let value = p.parse_ascii_integer(len, radix, padding)?;
+        p.parse_slice(1)?;
+
+        *self = p;
+        Ok(value)
+    }
+}
+
+/// Radix must be supported by [`char::to_digit`], that panics otherwise.
+fn check_radix(radix: u32) -> Result<(), BytesParserError> {
+    if (2..=36).contains(&radix) {
+        Ok(())
+    } else {
+        Err(BytesParserError::InvalidAsciiRadixError(radix))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{AsciiPadding, BytesParser, BytesParserError};
+
+    #[test]
+    fn parse_ascii_numbers() {
+        let input: &[u8] = &[
+            b'0', b'0', b'0', b'0', b'6', b'4', b'4', 0x00, //< TAR mode, octal
+            b'1', b'2', b'3', b'4', b' ', b' ', //< ar size, decimal
+            b'0', b'0', b'0', b'0', b'1', b'A', b'f', b'F', //< cpio, hex
+            b' ', b'-', b'4', b'2', //< signed decimal
+        ];
+
+        let mut p = BytesParser::from(input);
+
+        assert_eq!(p.parse_ascii_octal::<u32>(8).unwrap(), 0o644);
+        assert_eq!(p.parse_ascii_decimal::<u64>(6).unwrap(), 1234);
+        assert_eq!(p.parse_ascii_hex::<u16>(8).unwrap(), 0x1AFF);
+        assert_eq!(p.parse_ascii_decimal::<i8>(4).unwrap(), -42);
+        assert!(p.is_at_end());
+    }
+
+    #[test]
+    fn parse_ascii_delimited_number() {
+        let input: &[u8] = b"1a3;ext\r\n";
+
+        let mut p = BytesParser::from(input);
+
+        let len = p.find(b";").unwrap() - p.position();
+        assert_eq!(p.parse_ascii_integer::<usize>(len, 16, AsciiPadding::None).unwrap(), 0x1A3);
+        assert_eq!(p.position(), 3);
+
+        p.reset();
+        assert_eq!(p.parse_ascii_integer_until::<usize>(b';', 16, AsciiPadding::None).unwrap(), 0x1A3);
+        assert_eq!(p.position(), 4);
+        assert_eq!(
+            p.parse_ascii_integer_until::<usize>(b'\r', 16, AsciiPadding::None).unwrap_err(),
+            BytesParserError::InvalidAsciiNumberError(5)
+        );
+        assert_eq!(
+            p.parse_ascii_integer_until::<usize>(b'#', 16, AsciiPadding::None).unwrap_err(),
+            BytesParserError::PatternNotFoundError(b"#".to_vec())
+        );
+        assert_eq!(p.position(), 4);
+    }
+
+    #[test]
+    fn try_parsing_invalid_ascii_numbers() {
+        let input: &[u8] = b" 18 3 \x00\x00\x00\x00 300-129";
+
+        let mut p = BytesParser::from(input);
+
+        assert_eq!(p.parse_ascii_decimal::<u32>(6).unwrap_err(), BytesParserError::InvalidAsciiNumberError(3));
+        assert_eq!(
+            p.parse_ascii_integer::<u32>(3, 10, AsciiPadding::None).unwrap_err(),
+            BytesParserError::InvalidAsciiNumberError(0)
+        );
+        assert_eq!(p.parse_ascii_octal::<u32>(3).unwrap_err(), BytesParserError::InvalidAsciiNumberError(2));
+        assert!(p.is_at_start());
+
+        assert!(p.move_at(6).is_ok());
+        assert_eq!(p.parse_ascii_decimal::<u32>(4).unwrap_err(), BytesParserError::InvalidAsciiNumberError(10));
+        assert!(p.move_at(10).is_ok());
+        assert_eq!(
+            p.parse_ascii_decimal::<u8>(4).unwrap_err(),
+            BytesParserError::AsciiNumberOverflowError("u8".to_string(), 10)
+        );
+        assert!(p.move_at(14).is_ok());
+        assert_eq!(
+            p.parse_ascii_decimal::<u32>(4).unwrap_err(),
+            BytesParserError::AsciiNumberOverflowError("u32".to_string(), 14)
+        );
+        assert_eq!(
+            p.parse_ascii_decimal::<i8>(4).unwrap_err(),
+            BytesParserError::AsciiNumberOverflowError("i8".to_string(), 14)
+        );
+        assert_eq!(p.parse_ascii_decimal::<i16>(4).unwrap(), -129);
+    }
+
+    #[test]
+    fn try_parsing_ascii_numbers_with_invalid_radix() {
+        let input: &[u8] = b"101;";
+
+        let mut p = BytesParser::from(input);
+
+        assert_eq!(
+            p.parse_ascii_integer::<u32>(3, 1, AsciiPadding::None).unwrap_err(),
+            BytesParserError::InvalidAsciiRadixError(1)
+        );
+        assert_eq!(
+            p.parse_ascii_integer::<u32>(3, 37, AsciiPadding::None).unwrap_err(),
+            BytesParserError::InvalidAsciiRadixError(37)
+        );
+        assert_eq!(
+            p.parse_ascii_integer_until::<u32>(b';', 0, AsciiPadding::None).unwrap_err(),
+            BytesParserError::InvalidAsciiRadixError(0)
+        );
+        assert!(p.is_at_start());
+        assert_eq!(p.parse_ascii_integer_until::<u32>(b';', 2, AsciiPadding::None).unwrap(), 0b101);
+        assert!(p.is_at_end());
+    }
+}
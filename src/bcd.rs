@@ -0,0 +1,277 @@
+use crate::errors::BytesParserError;
+use crate::parser::BytesParser;
+
+/// Decode the 2 nibbles of a BCD byte, high nibble first.
+///
+/// It produces an error if any of the nibbles is not a decimal digit (i.e. `0x0..=0x9`).
+fn bcd_digits(byte: u8, position: usize) -> Result<[u8; 2], BytesParserError> {
+    let (high, low) = (byte >> 4, byte & 0x0F);
+    if high > 9 {
+        return Err(BytesParserError::InvalidBcdError(high, position));
+    }
+    if low > 9 {
+        return Err(BytesParserError::InvalidBcdError(low, position));
+    }
+
+    Ok([high, low])
+}
+
+/// Validate the nibbles of a packed decimal, and return whether it's negative.
+///
+/// It produces an error if any of the digits nibbles is not a decimal digit, or if the sign nibble
+/// (i.e. the low nibble of the last byte) is not a sign. `bytes` must not be empty.
+fn packed_decimal_sign(bytes: &[u8], position: usize) -> Result<bool, BytesParserError> {
+    let (last, leading) = bytes.split_last().unwrap();
+    for (i, byte) in leading.iter().enumerate() {
+        bcd_digits(*byte, position + i)?;
+    }
+
+    let last_position = position + leading.len();
+    let (last_digit, sign) = (last >> 4, last & 0x0F);
+    if last_digit > 9 {
+        return Err(BytesParserError::InvalidBcdError(last_digit, last_position));
+    }
+    match sign {
+        0xA | 0xC | 0xE | 0xF => Ok(false),
+        0xB | 0xD => Ok(true),
+        _ => Err(BytesParserError::InvalidBcdError(sign, last_position)),
+    }
+}
+
+/// Iterate over the digits of an (already validated) packed decimal, most significant first.
+fn packed_decimal_digits(bytes: &[u8]) -> impl Iterator<Item = u8> + '_ {
+    bytes.iter().flat_map(|byte| [byte >> 4, byte & 0x0F]).take(bytes.len() * 2 - 1)
+}
+
+impl<'a> BytesParser<'a> {
+    /// Parse `n_bytes` of [BCD](https://en.wikipedia.org/wiki/Binary-coded_decimal) into a [`u64`],
+    /// and update the internal cursor accordingly.
+    ///
+    /// Every byte holds 2 decimal digits, one per nibble, with the most significant digit in the
+    /// high nibble of the first byte. It produces an error if a nibble is not a decimal digit,
+    /// or if the value does not fit in a [`u64`]. In case of error, the cursor is not moved.
+    ///
+    /// # Arguments
+    ///
+    /// * `n_bytes` - Amount of bytes to parse: the value will be made of `2 * n_bytes` digits.
+    pub fn parse_bcd(&mut self, n_bytes: usize) -> Result<u64, BytesParserError> {
+        let mut p = *self;
+        let bytes = p.parse_slice(n_bytes)?;
+
+        let mut value: u64 = 0;
+        for (i, byte) in bytes.iter().enumerate() {
+            for digit in bcd_digits(*byte, self.position() + i)? {
+                value = value
+                    .checked_mul(10)
+                    .and_then(|v| v.checked_add(digit as u64))
+                    .ok_or(BytesParserError::BcdOverflowError(n_bytes))?;
+            }
+        }
+
+        *self = p;
+        Ok(value)
+    }
+
+    /// Parse `n_bytes` of [BCD](https://en.wikipedia.org/wiki/Binary-coded_decimal) into a
+    /// [`String`] of decimal digits, and update the internal cursor accordingly.
+    ///
+    /// Differently from [`Self::parse_bcd`], leading zeros are preserved and there is no limit
+    /// to the amount of digits: this is useful for values like dates or phone numbers.
+    /// In case of error, the cursor is not moved.
+    ///
+    /// # Arguments
+    ///
+    /// * `n_bytes` - Amount of bytes to parse: the string will be made of `2 * n_bytes` digits.
+    pub fn parse_bcd_str(&mut self, n_bytes: usize) -> Result<String, BytesParserError> {
+        let mut p = *self;
+        let bytes = p.parse_slice(n_bytes)?;
+
+        let mut digits = String::with_capacity(n_bytes * 2);
+        for (i, byte) in bytes.iter().enumerate() {
+            for digit in bcd_digits(*byte, self.position() + i)? {
+                digits.push((b'0' + digit) as char);
+            }
+        }
+
+        *self = p;
+        Ok(digits)
+    }
+
+    /// Parse `n_bytes` of [packed decimal](https://en.wikipedia.org/wiki/Binary-coded_decimal#Packed_BCD)
+    /// (i.e. COBOL `COMP-3`) into a decimal [`String`], and update the internal cursor accordingly.
+    ///
+    /// Every nibble holds a decimal digit, except for the low nibble of the last byte that holds
+    /// the sign: `0xC`, `0xA`, `0xE` and `0xF` (unsigned) are positive, `0xB` and `0xD` are negative.
+    /// It produces an error if a digit or the sign nibble are invalid, or if `scale` exceeds
+    /// the amount of digits. In case of error, the cursor is not moved.
+    ///
+    /// The returned string has the form `[-]<integer>[.<fraction>]`, with leading zeros
+    /// of the integer part removed: for example `0x01 0x23 0x4D` with `scale` of `2` is `-12.34`.
+    /// To get the unscaled value as an integer instead, see [`Self::parse_packed_decimal_i64`].
+    ///
+    /// # Arguments
+    ///
+    /// * `n_bytes` - Amount of bytes to parse: the value will be made of `2 * n_bytes - 1` digits.
+    /// * `scale` - Amount of digits that follow the (implied) decimal point: at most `2 * n_bytes - 1`.
+    pub fn parse_packed_decimal(&mut self, n_bytes: usize, scale: usize) -> Result<String, BytesParserError> {
+        if n_bytes == 0 {
+            return Err(BytesParserError::NotEnoughBytesForTypeError("packed decimal".to_string()));
+        }
+
+        let mut p = *self;
+        let bytes = p.parse_slice(n_bytes)?;
+
+        let digits_count = n_bytes * 2 - 1;
+        if scale > digits_count {
+            return Err(BytesParserError::PackedDecimalScaleError(scale, digits_count));
+        }
+
+        let negative = packed_decimal_sign(bytes, self.position())?;
+        let digits: Vec<u8> = packed_decimal_digits(bytes).collect();
+
+        let (integer, fraction) = digits.split_at(digits.len() - scale);
+        let integer_start = integer.iter().position(|d| *d != 0).unwrap_or(integer.len());
+
+        let mut result = String::with_capacity(digits.len() + 3);
+        if negative && digits.iter().any(|d| *d != 0) {
+            result.push('-');
+        }
+        // There is always at least 1 digit before the decimal point
+        if integer_start == integer.len() {
+            result.push('0');
+        }
+        result.extend(integer[integer_start..].iter().map(|d| (b'0' + d) as char));
+        if !fraction.is_empty() {
+            result.push('.');
+            result.extend(fraction.iter().map(|d| (b'0' + d) as char));
+        }
+
+        *self = p;
+        Ok(result)
+    }
+
+    /// Parse `n_bytes` of [packed decimal](https://en.wikipedia.org/wiki/Binary-coded_decimal#Packed_BCD)
+    /// (i.e. COBOL `COMP-3`) into its unscaled [`i64`] value, and update the internal cursor accordingly.
+    ///
+    /// The nibbles are the same as in [`Self::parse_packed_decimal`], but the implied decimal point
+    /// is left to the caller: for example `0x01 0x23 0x4D` is `-1234` (i.e. `-12.34` with a scale of `2`).
+    /// It produces an error if a digit or the sign nibble are invalid, or if the value does not fit
+    /// in an [`i64`]. In case of error, the cursor is not moved.
+    ///
+    /// # Arguments
+    ///
+    /// * `n_bytes` - Amount of bytes to parse: the value will be made of `2 * n_bytes - 1` digits.
+    pub fn parse_packed_decimal_i64(&mut self, n_bytes: usize) -> Result<i64, BytesParserError> {
+        if n_bytes == 0 {
+            return Err(BytesParserError::NotEnoughBytesForTypeError("packed decimal".to_string()));
+        }
+
+        let mut p = *self;
+        let bytes = p.parse_slice(n_bytes)?;
+        let negative = packed_decimal_sign(bytes, self.position())?;
+
+        // Negative values are accumulated as such, so that `i64::MIN` doesn't overflow
+        let mut value: i64 = 0;
+        for digit in packed_decimal_digits(bytes) {
+            let digit = if negative {
+                -(digit as i64)
+            } else {
+                digit as i64
+            };
+            value = value
+                .checked_mul(10)
+                .and_then(|v| v.checked_add(digit))
+                .ok_or(BytesParserError::BcdOverflowError(n_bytes))?;
+        }
+
+        *self = p;
+        Ok(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{BytesParser, BytesParserError};
+
+    #[test]
+    fn parse_bcd() {
+        let input: &[u8] = &[
+            0x20, 0x24, 0x12, 0x31, //< date 2024-12-31
+            0x00, 0x39, 0x33, 0x12, 0x34, //< phone number
+        ];
+
+        let mut p = BytesParser::from(input);
+
+        assert_eq!(p.parse_bcd(4).unwrap(), 20241231);
+        assert_eq!(p.parse_bcd_str(5).unwrap(), "0039331234");
+        assert!(p.is_at_end());
+    }
+
+    #[test]
+    fn parse_packed_decimal() {
+        let input: &[u8] = &[
+            0x01, 0x23, 0x4D, //< -12.34
+            0x00, 0x5C, //< +0.05
+            0x12, 0x3F, //< 123, unsigned
+            0x00, 0x0D, //< -0
+        ];
+
+        let mut p = BytesParser::from(input);
+
+        assert_eq!(p.parse_packed_decimal(3, 2).unwrap(), "-12.34");
+        assert_eq!(p.parse_packed_decimal(2, 3).unwrap(), "0.005");
+        assert_eq!(p.parse_packed_decimal(2, 0).unwrap(), "123");
+        assert_eq!(p.parse_packed_decimal(2, 1).unwrap(), "0.0");
+        assert!(p.is_at_end());
+
+        p.reset();
+        assert_eq!(p.parse_packed_decimal_i64(3).unwrap(), -1234);
+        assert_eq!(p.parse_packed_decimal_i64(2).unwrap(), 5);
+        assert_eq!(p.parse_packed_decimal_i64(2).unwrap(), 123);
+        assert_eq!(p.parse_packed_decimal_i64(2).unwrap(), 0);
+        assert!(p.is_at_end());
+
+        let input: &[u8] = &[
+            0x92, 0x23, 0x37, 0x20, 0x36, 0x85, 0x47, 0x75, 0x80, 0x8D, //< i64::MIN
+            0x92, 0x23, 0x37, 0x20, 0x36, 0x85, 0x47, 0x75, 0x80, 0x8C, //< i64::MAX + 1
+        ];
+
+        let mut p = BytesParser::from(input);
+
+        assert_eq!(p.parse_packed_decimal_i64(10).unwrap(), i64::MIN);
+        assert_eq!(p.parse_packed_decimal_i64(10).unwrap_err(), BytesParserError::BcdOverflowError(10));
+        assert_eq!(p.position(), 10);
+    }
+
+    #[test]
+    fn try_parsing_invalid_bcd() {
+        let input: &[u8] = &[0xA1, 0x23, 0x12, 0x34, 0x12, 0x34];
+
+        let mut p = BytesParser::from(input);
+
+        assert_eq!(p.parse_bcd(2).unwrap_err(), BytesParserError::InvalidBcdError(0xA, 0));
+        assert_eq!(p.parse_packed_decimal(2, 0).unwrap_err(), BytesParserError::InvalidBcdError(0xA, 0));
+        assert_eq!(p.position(), 0);
+
+        assert_eq!(p.parse_bcd(6).unwrap_err(), BytesParserError::InvalidBcdError(0xA, 0));
+        assert!(p.move_forward(2).is_ok());
+        assert_eq!(p.parse_packed_decimal(2, 0).unwrap_err(), BytesParserError::InvalidBcdError(0x4, 3));
+        assert_eq!(p.parse_packed_decimal_i64(2).unwrap_err(), BytesParserError::InvalidBcdError(0x4, 3));
+        assert_eq!(
+            p.parse_packed_decimal_i64(0).unwrap_err(),
+            BytesParserError::NotEnoughBytesForTypeError("packed decimal".to_string())
+        );
+        assert_eq!(p.position(), 2);
+
+        let input: &[u8] = &[0x99; 10];
+        let mut p = BytesParser::from(input);
+
+        assert_eq!(p.parse_bcd(10).unwrap_err(), BytesParserError::BcdOverflowError(10));
+        assert_eq!(p.parse_packed_decimal(2, 4).unwrap_err(), BytesParserError::PackedDecimalScaleError(4, 3));
+        assert_eq!(
+            p.parse_packed_decimal(2, usize::MAX).unwrap_err(),
+            BytesParserError::PackedDecimalScaleError(usize::MAX, 3)
+        );
+        assert_eq!(p.parse_bcd_str(10).unwrap(), "99999999999999999999");
+    }
+}
@@ -0,0 +1,281 @@
+use crate::errors::BytesParserError;
+use crate::parser::BytesParser;
+use crate::scalar::Scalar;
+
+use std::convert::TryInto;
+use std::fmt::Display;
+use std::iter::FusedIterator;
+
+/// Iterator of records, parsed one after the other from a [`BytesParser`].
+///
+/// It's created by [`BytesParser::iter_with`].
+#[derive(Debug)]
+pub struct Records<'p, 'a, F> {
+    parser: &'p mut BytesParser<'a>,
+    f: F,
+    offset: usize,
+    done: bool,
+}
+
+impl<'p, 'a, F> Records<'p, 'a, F> {
+    /// Returns the position at which the last record returned by [`Iterator::next`] started.
+    ///
+    /// Before the first call to [`Iterator::next`], this is the position at which
+    /// the first record will start.
+    pub const fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Turns this into an iterator that yields each record together with its starting position.
+    pub fn with_offsets(self) -> RecordsWithOffsets<'p, 'a, F> {
+        RecordsWithOffsets {
+            records: self,
+        }
+    }
+}
+
+impl<'a, T, F> Iterator for Records<'_, 'a, F>
+where
+    F: FnMut(&mut BytesParser<'a>) -> Result<T, BytesParserError>,
+{
+    type Item = Result<T, BytesParserError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done || self.parser.is_at_end() {
+            return None;
+        }
+
+        self.offset = self.parser.position();
+        let record = (self.f)(self.parser);
+
+        // Stop on the first error, or if the closure did not consume any byte (i.e. it would loop forever)
+        self.done = record.is_err() || self.parser.position() == self.offset;
+
+        Some(record)
+    }
+}
+
+impl<'a, T, F> FusedIterator for Records<'_, 'a, F> where F: FnMut(&mut BytesParser<'a>) -> Result<T, BytesParserError> {}
+
+/// Iterator of records, each with its starting position, parsed one after the other from a [`BytesParser`].
+///
+/// It's created by [`Records::with_offsets`].
+#[derive(Debug)]
+pub struct RecordsWithOffsets<'p, 'a, F> {
+    records: Records<'p, 'a, F>,
+}
+
+impl<'a, T, F> Iterator for RecordsWithOffsets<'_, 'a, F>
+where
+    F: FnMut(&mut BytesParser<'a>) -> Result<T, BytesParserError>,
+{
+    type Item = Result<(usize, T), BytesParserError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let record = self.records.next()?;
+
+        Some(record.map(|r| (self.records.offset(), r)))
+    }
+}
+
+impl<'a, T, F> FusedIterator for RecordsWithOffsets<'_, 'a, F> where
+    F: FnMut(&mut BytesParser<'a>) -> Result<T, BytesParserError>
+{
+}
+
+impl<'a> BytesParser<'a> {
+    /// Parse `count` elements into a [`Vec`], calling `f` once per element,
+    /// and update the internal cursor accordingly.
+    ///
+    /// It produces an error if `count` exceeds [`Self::max_count`], or if `f` produces an error
+    /// for any of the elements. In case of error, the cursor is not moved.
+    ///
+    /// To guard against huge allocations, the memory for the elements is pre-allocated only up to
+    /// [`Self::parseable`] elements.
+    ///
+    /// # Arguments
+    ///
+    /// * `count` - Amount of elements to parse.
+    /// * `f` - The closure that parses a single element: it receives this [`BytesParser`].
+    pub fn parse_vec_with<T, F>(&mut self, count: usize, mut f: F) -> Result<Vec<T>, BytesParserError>
+    where
+        F: FnMut(&mut BytesParser<'a>) -> Result<T, BytesParserError>,
+    {
+        if count > self.max_count() {
+            return Err(BytesParserError::CountOutOfRangeError(count.to_string(), self.max_count()));
+        }
+
+        let mut p = *self;
+        let mut elements = Vec::with_capacity(count.min(p.parseable()));
+        for _ in 0..count {
+            elements.push(f(&mut p)?);
+        }
+
+        *self = p;
+        Ok(elements)
+    }
+
+    /// Parse a count prefix of scalar type `P`, then that many elements into a [`Vec`],
+    /// calling `f` once per element, and update the internal cursor accordingly.
+    ///
+    /// The count prefix is parsed using the [`crate::ParsingEndian`] currently in use.
+    /// It produces an error if the count is negative or exceeds [`Self::max_count`],
+    /// or if `f` produces an error for any of the elements.
+    /// In case of error, the cursor is not moved.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - The closure that parses a single element: it receives this [`BytesParser`].
+    pub fn parse_counted<P, T, F>(&mut self, f: F) -> Result<Vec<T>, BytesParserError>
+    where
+        P: Scalar + TryInto<usize> + Display,
+        F: FnMut(&mut BytesParser<'a>) -> Result<T, BytesParserError>,
+    {
+        let mut p = *self;
+        let prefix: P = p.parse_scalar(p.endian())?;
+        let count =
+            prefix.try_into().map_err(|_| BytesParserError::CountOutOfRangeError(prefix.to_string(), p.max_count()))?;
+
+        let elements = p.parse_vec_with(count, f)?;
+
+        *self = p;
+        Ok(elements)
+    }
+
+    /// Returns an iterator of records, calling `f` repeatedly to parse one record after the other,
+    /// until [`Self::is_at_end`].
+    ///
+    /// The iteration stops after the first error produced by `f`, leaving the cursor wherever `f`
+    /// left it. It also stops after a record for which `f` did not consume any byte,
+    /// as calling it again would produce the same record forever.
+    ///
+    /// See [`Records::offset`] and [`Records::with_offsets`] to know where each record started.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - The closure that parses a single record: it receives this [`BytesParser`].
+    pub fn iter_with<T, F>(&mut self, f: F) -> Records<'_, 'a, F>
+    where
+        F: FnMut(&mut BytesParser<'a>) -> Result<T, BytesParserError>,
+    {
+        Records {
+            offset: self.position(),
+            parser: self,
+            f,
+            done: false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{BytesParser, BytesParserError, ParsingEndian};
+
+    #[test]
+    fn parse_vec_with() {
+        let input: &[u8] = &[
+            0x00, 0x01, 0x41, //< u16 + char
+            0x00, 0x02, 0x42, //< u16 + char
+        ];
+
+        let mut p = BytesParser::from(input);
+
+        let elements = p.parse_vec_with(2, |p| Ok((p.parse_u16()?, p.parse_u8()? as char))).unwrap();
+        assert_eq!(elements, vec![(1, 'A'), (2, 'B')]);
+        assert!(p.is_at_end());
+
+        p.reset();
+        assert_eq!(
+            p.parse_vec_with(4, |p| p.parse_u16()).unwrap_err(),
+            BytesParserError::NotEnoughBytesForTypeError("u16".to_string())
+        );
+        assert!(p.is_at_start());
+    }
+
+    #[test]
+    fn parse_counted() {
+        let input: &[u8] = &[
+            0x03, 0x00, 0x00, 0x00, //< u32 count, little-endian
+            0x0A, 0x00, 0x0B, 0x00, 0x0C, 0x00, //< 3 x u16, little-endian
+        ];
+
+        let mut p = BytesParser::from(input);
+        p.set_endian(ParsingEndian::LE);
+
+        assert_eq!(p.parse_counted::<u32, _, _>(|p| p.parse_u16()).unwrap(), vec![0x0A, 0x0B, 0x0C]);
+        assert!(p.is_at_end());
+
+        p.reset();
+        assert_eq!(p.parse_counted::<u8, _, _>(|p| p.parse_u8()).unwrap(), vec![0x00, 0x00, 0x00]);
+    }
+
+    #[test]
+    fn iter_with() {
+        let input: &[u8] = &[
+            0x02, 0x41, 0x42, //< record: u8 length + bytes
+            0x00, //< empty record
+            0x01, 0x43, //< record: u8 length + bytes
+        ];
+
+        let mut p = BytesParser::from(input);
+
+        let records = p.iter_with(|p| {
+            let len = p.parse_u8()? as usize;
+            p.parse_slice(len)
+        });
+        assert_eq!(records.collect::<Result<Vec<_>, _>>().unwrap(), vec![&b"AB"[..], &b""[..], &b"C"[..]]);
+        assert!(p.is_at_end());
+
+        p.reset();
+        let records = p.iter_with(|p| p.parse_u8());
+        let offsets = records.with_offsets().map(|r| r.unwrap().0).collect::<Vec<_>>();
+        assert_eq!(offsets, vec![0, 1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn iter_with_stops_on_first_error() {
+        let input: &[u8] = &[0x00, 0x01, 0x00, 0x02, 0x00];
+
+        let mut p = BytesParser::from(input);
+
+        let mut records = p.iter_with(|p| p.parse_u16());
+        assert_eq!(records.offset(), 0);
+        assert_eq!(records.next(), Some(Ok(1)));
+        assert_eq!(records.next(), Some(Ok(2)));
+        assert_eq!(records.offset(), 2);
+        assert_eq!(records.next(), Some(Err(BytesParserError::NotEnoughBytesForTypeError("u16".to_string()))));
+        assert_eq!(records.offset(), 4);
+        assert_eq!(records.next(), None);
+
+        // A closure that does not consume any byte stops the iteration
+        p.reset();
+        assert_eq!(p.iter_with(|p| Ok(p.position())).count(), 1);
+    }
+
+    #[test]
+    fn try_parsing_counted_out_of_range() {
+        let input: &[u8] = &[0xFF, 0xFF, 0xFF, 0xFF, 0x00];
+
+        let mut p = BytesParser::from(input);
+
+        assert_eq!(
+            p.parse_counted::<i32, _, _>(|p| p.parse_u8()).unwrap_err(),
+            BytesParserError::CountOutOfRangeError("-1".to_string(), usize::MAX)
+        );
+
+        p.set_max_count(1000);
+        assert_eq!(
+            p.parse_counted::<u32, _, _>(|p| p.parse_u8()).unwrap_err(),
+            BytesParserError::CountOutOfRangeError("4294967295".to_string(), 1000)
+        );
+        assert!(p.is_at_start());
+
+        // Sub-parsers inherit the maximum count
+        let mut ps = p.from_slice(2).unwrap();
+        assert_eq!(ps.max_count(), 1000);
+        assert_eq!(
+            ps.parse_vec_with(1001, |p| p.parse_u8()).unwrap_err(),
+            BytesParserError::CountOutOfRangeError("1001".to_string(), 1000)
+        );
+    }
+}
@@ -19,6 +19,35 @@ pub enum ParsingEndian {
     /// A little-endian system stores the least significant byte of a word at the smallest memory
     /// address, and the most significant byte at the largest.
     LE,
+
+    /// Read using the native byte order system of the target platform.
+    ///
+    /// This is either [`ParsingEndian::BE`] or [`ParsingEndian::LE`], depending on the platform
+    /// this crate is compiled for. Useful when parsing bytes that were produced on the same host
+    /// (e.g. memory-mapped files).
+    Native,
+
+    /// Read using the middle-endian byte order system of the PDP-11.
+    ///
+    /// A PDP-11 system stores scalar values as a sequence of 16-bit words, with the most
+    /// significant word at the smallest memory address, but each word in little-endian.
+    /// For example, the `u32` value `0x0A0B0C0D` is stored as `0x0B 0x0A 0x0D 0x0C`.
+    ///
+    /// For scalar types of 16 bits this is equivalent to [`ParsingEndian::LE`].
+    ///
+    /// **NOTE:** This does not decode ARM FPA doubles, that are made of 32-bit words instead:
+    /// see [`ParsingEndian::FPA`].
+    PDP,
+
+    /// Read using the middle-endian byte order system of the ARM FPA (Floating Point Accelerator).
+    ///
+    /// An ARM FPA system stores 64-bit doubles as a sequence of 32-bit words, with the most
+    /// significant word at the smallest memory address, but each word in little-endian.
+    /// For example, the `f64` value `1.0` (i.e. `0x3FF0000000000000`) is stored as
+    /// `0x00 0x00 0xF0 0x3F 0x00 0x00 0x00 0x00`.
+    ///
+    /// For scalar types of 32 bits or less this is equivalent to [`ParsingEndian::LE`].
+    FPA,
 }
 
 impl Default for ParsingEndian {
@@ -27,3 +56,96 @@ impl Default for ParsingEndian {
         ParsingEndian::BE
     }
 }
+
+/// A magic pattern that, when found in the leading bytes of some input, declares its [ParsingEndian].
+///
+/// Many binary formats declare their byte order in their header, using a pattern of bytes
+/// that reads differently depending on the endian system used to write it.
+/// See [`DEFAULT_ENDIAN_MAGICS`] for a list of well known ones, and
+/// [`crate::BytesParser::detect_endian`] for how to use them.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct EndianMagic {
+    /// Offset of the pattern, relative to where detection starts.
+    pub offset: usize,
+
+    /// Bytes that must be found at `offset`.
+    pub pattern: &'static [u8],
+
+    /// Bytes that must also be found at the start, where detection starts (empty if none).
+    ///
+    /// This is useful when `pattern` is not at the start, and on its own it's too weak
+    /// to tell a format apart from unrelated data.
+    pub signature: &'static [u8],
+
+    /// The [ParsingEndian] declared by the pattern, when found.
+    pub endian: ParsingEndian,
+}
+
+impl EndianMagic {
+    /// Creates a new [EndianMagic].
+    ///
+    /// # Arguments
+    ///
+    /// * `offset` - Offset of the pattern, relative to where detection starts.
+    /// * `pattern` - Bytes that must be found at `offset`.
+    /// * `endian` - The [ParsingEndian] declared by the pattern, when found.
+    pub const fn new(offset: usize, pattern: &'static [u8], endian: ParsingEndian) -> Self {
+        EndianMagic {
+            offset,
+            pattern,
+            signature: &[],
+            endian,
+        }
+    }
+
+    /// Returns this [EndianMagic], also requiring the given `signature` at the start.
+    ///
+    /// # Arguments
+    ///
+    /// * `signature` - Bytes that must also be found at the start, where detection starts.
+    pub const fn with_signature(mut self, signature: &'static [u8]) -> Self {
+        self.signature = signature;
+        self
+    }
+
+    /// Returns [`true`] if the pattern is found at `offset` of the given `bytes`,
+    /// and they start with the signature.
+    pub fn matches(&self, bytes: &[u8]) -> bool {
+        let Some(end) = self.offset.checked_add(self.pattern.len()) else {
+            return false;
+        };
+
+        bytes.starts_with(self.signature) && bytes.get(self.offset..end) == Some(self.pattern)
+    }
+}
+
+/// Well known [EndianMagic] patterns, found at the start of common binary formats.
+///
+/// It covers TIFF and BigTIFF (`II`/`MM`), ELF (`EI_DATA`), pcap (microseconds and nanoseconds),
+/// pcapng (Section Header Block type and byte-order magic) and the UTF-16 byte order mark.
+///
+/// To detect additional formats, build a new table that includes these patterns, for example
+/// `[DEFAULT_ENDIAN_MAGICS, &my_magics].concat()`.
+pub const DEFAULT_ENDIAN_MAGICS: &[EndianMagic] = &[
+    // TIFF and BigTIFF
+    EndianMagic::new(0, b"II\x2A\x00", ParsingEndian::LE),
+    EndianMagic::new(0, b"MM\x00\x2A", ParsingEndian::BE),
+    EndianMagic::new(0, b"II\x2B\x00", ParsingEndian::LE),
+    EndianMagic::new(0, b"MM\x00\x2B", ParsingEndian::BE),
+    // ELF: magic, EI_CLASS (32 or 64 bits), EI_DATA
+    EndianMagic::new(0, b"\x7FELF\x01\x01", ParsingEndian::LE),
+    EndianMagic::new(0, b"\x7FELF\x02\x01", ParsingEndian::LE),
+    EndianMagic::new(0, b"\x7FELF\x01\x02", ParsingEndian::BE),
+    EndianMagic::new(0, b"\x7FELF\x02\x02", ParsingEndian::BE),
+    // pcap: microseconds and nanoseconds resolution
+    EndianMagic::new(0, &[0xA1, 0xB2, 0xC3, 0xD4], ParsingEndian::BE),
+    EndianMagic::new(0, &[0xD4, 0xC3, 0xB2, 0xA1], ParsingEndian::LE),
+    EndianMagic::new(0, &[0xA1, 0xB2, 0x3C, 0x4D], ParsingEndian::BE),
+    EndianMagic::new(0, &[0x4D, 0x3C, 0xB2, 0xA1], ParsingEndian::LE),
+    // UTF-16 byte order mark
+    EndianMagic::new(0, &[0xFE, 0xFF], ParsingEndian::BE),
+    EndianMagic::new(0, &[0xFF, 0xFE], ParsingEndian::LE),
+    // pcapng: byte-order magic of the Section Header Block, after its block type and length
+    EndianMagic::new(8, &[0x1A, 0x2B, 0x3C, 0x4D], ParsingEndian::BE).with_signature(&[0x0A, 0x0D, 0x0D, 0x0A]),
+    EndianMagic::new(8, &[0x4D, 0x3C, 0x2B, 0x1A], ParsingEndian::LE).with_signature(&[0x0A, 0x0D, 0x0D, 0x0A]),
+];
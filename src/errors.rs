@@ -24,11 +24,87 @@ pub enum BytesParserError {
     #[error("Moving cursor to/by {0} would place it out-of-bound: bytes array length is {1} and cursor is at {2}")]
     CursorOutOfBoundError(isize, usize, usize),
 
-    /// Failed to parse a UTF-8 [String] from the given bytes.
-    #[error("Failed to parse UTF-8 string: {0}")]
-    StringParseError(#[source] Utf8Error),
+    /// Failed to parse a UTF-8 [String] from the given bytes, because of the invalid byte at given position.
+    ///
+    /// The position is absolute, relative to the original bytes array (see [BytesParser::absolute_position]).
+    #[error("Failed to parse UTF-8 string at position {1}: {0}")]
+    StringParseError(#[source] Utf8Error, usize),
+
+    /// Failed to decode a string of given encoding, because of an invalid byte sequence starting at given position.
+    ///
+    /// The position is absolute, relative to the original bytes array (see [BytesParser::absolute_position]).
+    #[error("Failed to decode {0} string: invalid byte sequence at position {1}")]
+    StringDecodeError(String, usize),
 
     /// Failed to parse a [char] from a [u32] worth of bytes (i.e. 4 bytes).
     #[error("Invalid char found in u32")]
     InvalidU32ForCharError,
+
+    /// Failed to parse a [bool] from the [u8] found at given position, as it's neither `0` nor `1`.
+    #[error("Invalid bool value {0} at position {1}")]
+    InvalidBoolError(u8, usize),
+
+    /// Failed to parse an enum from the discriminant found at given position, as it's unknown.
+    #[error("Unknown enum discriminant {0} at position {1}")]
+    UnknownDiscriminantError(String, usize),
+
+    /// Failed to parse flags from the bits found at given position, as some of them are unknown.
+    #[error("Unknown flag bits {0} at position {1}")]
+    UnknownFlagsError(String, usize),
+
+    /// Failed to parse a timestamp from the value found at given position, as it's out of range.
+    #[error("Timestamp {0} at position {1} is out of range")]
+    TimestampOutOfRangeError(String, usize),
+
+    /// Found an invalid nibble (i.e. not a decimal digit, or not a valid sign) in the byte at given position,
+    /// while parsing BCD or packed decimal.
+    #[error("Invalid BCD nibble {0:#X} in byte at position {1}")]
+    InvalidBcdError(u8, usize),
+
+    /// BCD value of given bytes does not fit in the integer type it was parsed into.
+    #[error("BCD value of {0} bytes overflows the parsed integer type")]
+    BcdOverflowError(usize),
+
+    /// Failed to parse a packed decimal, as the given scale exceeds its amount of digits.
+    #[error("Packed decimal scale {0} exceeds its {1} digits")]
+    PackedDecimalScaleError(usize, usize),
+
+    /// Count of elements of a collection is negative, or exceeds the maximum (i.e. [BytesParser::max_count]).
+    #[error("Count {0} is out of range: maximum count is {1}")]
+    CountOutOfRangeError(String, usize),
+
+    /// Found a non-zero byte at given position, where zero padding was expected.
+    #[error("Found non-zero padding byte {0:#X} at position {1}")]
+    NonZeroPaddingError(u8, usize),
+
+    /// The given pattern of bytes was not found, in the bytes left (i.e. [BytesParser::parseable]).
+    #[error("Pattern {0:02X?} not found")]
+    PatternNotFoundError(Vec<u8>),
+
+    /// Line is longer than the maximum length (i.e. [BytesParser::max_line_length]).
+    #[error("Line exceeds the maximum length of {0} bytes")]
+    LineTooLongError(usize),
+
+    /// Found an invalid character at given position, while parsing an ASCII-encoded number.
+    #[error("Invalid ASCII number: unexpected character at position {0}")]
+    InvalidAsciiNumberError(usize),
+
+    /// ASCII-encoded number at given position does not fit in the integer type it was parsed into.
+    #[error("ASCII number at position {1} overflows {0}")]
+    AsciiNumberOverflowError(String, usize),
+
+    /// Radix given to parse an ASCII-encoded number is not in the supported range `2..=36`.
+    #[error("Invalid radix {0} for ASCII number: must be in the range 2..=36")]
+    InvalidAsciiRadixError(u32),
+
+    /// Found a value different from the one expected (e.g. a magic number), at given position.
+    #[error("Unexpected value at position {offset}: expected {expected}, found {found}")]
+    UnexpectedValue {
+        /// Position of the value.
+        offset: usize,
+        /// The value that was expected.
+        expected: String,
+        /// The value that was found instead.
+        found: String,
+    },
 }
@@ -0,0 +1,147 @@
+use crate::errors::BytesParserError;
+use crate::parser::BytesParser;
+use crate::scalar::Scalar;
+
+use std::fmt::Debug;
+
+macro_rules! build_expect_type_fn {
+    ($fn_name:ident, $parsed_type:ty) => {
+        #[doc = "Parse a `"]
+        #[doc=stringify!($parsed_type)]
+        #[doc = "`, verify it is equal to `expected`, and update the internal cursor accordingly.\n\n"]
+        #[doc = "It produces an error if the parsed value is different from `expected`:"]
+        #[doc = "in that case, the cursor is not moved."]
+        pub fn $fn_name(&mut self, expected: $parsed_type) -> Result<(), BytesParserError> {
+            self.expect_scalar(expected)
+        }
+    };
+}
+
+impl<'a> BytesParser<'a> {
+    build_expect_type_fn!(expect_i8, i8);
+    build_expect_type_fn!(expect_u8, u8);
+
+    build_expect_type_fn!(expect_i16, i16);
+    build_expect_type_fn!(expect_u16, u16);
+
+    build_expect_type_fn!(expect_i32, i32);
+    build_expect_type_fn!(expect_u32, u32);
+
+    build_expect_type_fn!(expect_i64, i64);
+    build_expect_type_fn!(expect_u64, u64);
+
+    build_expect_type_fn!(expect_i128, i128);
+    build_expect_type_fn!(expect_u128, u128);
+
+    build_expect_type_fn!(expect_f32, f32);
+    build_expect_type_fn!(expect_f64, f64);
+
+    build_expect_type_fn!(expect_isize, isize);
+    build_expect_type_fn!(expect_usize, usize);
+
+    /// Parse a slice of bytes, verify it is equal to `expected`, and update the internal cursor accordingly.
+    ///
+    /// This is useful to verify signatures and magic numbers (e.g. `b"\x89PNG\r\n\x1A\n"`).
+    /// It produces an error if [`BytesParser::parseable`] returns an amount inferior to the
+    /// length of `expected`, or if the parsed bytes are different from `expected`:
+    /// in that case, the cursor is not moved.
+    ///
+    /// # Arguments
+    ///
+    /// * `expected` - The bytes expected at [`Self::position`].
+    pub fn expect_bytes(&mut self, expected: &[u8]) -> Result<(), BytesParserError> {
+        let mut p = *self;
+        let found = p.parse_slice(expected.len())?;
+
+        if found != expected {
+            return Err(BytesParserError::UnexpectedValue {
+                offset: self.position(),
+                expected: format!("{expected:02X?}"),
+                found: format!("{found:02X?}"),
+            });
+        }
+
+        *self = p;
+        Ok(())
+    }
+
+    /// Parse a scalar type `T` using the [`crate::ParsingEndian`] currently in use, verify it is equal
+    /// to `expected`, and update the internal cursor accordingly.
+    fn expect_scalar<T: Scalar + PartialEq + Debug>(&mut self, expected: T) -> Result<(), BytesParserError> {
+        let mut p = *self;
+        let found: T = p.parse_scalar(p.endian())?;
+
+        if found != expected {
+            return Err(BytesParserError::UnexpectedValue {
+                offset: self.position(),
+                expected: format!("{expected:?}"),
+                found: format!("{found:?}"),
+            });
+        }
+
+        *self = p;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{BytesParser, BytesParserError, ParsingEndian};
+
+    #[test]
+    fn expect_values() {
+        let input: &[u8] = &[
+            0x89, 0x50, 0x4E, 0x47, 0x0D, 0x0A, 0x1A, 0x0A, //< PNG signature
+            0x00, 0x00, 0x00, 0x0D, //< u32
+            0x49, 0x48, 0x44, 0x52, //< "IHDR"
+            0x01, //< u8
+        ];
+
+        let mut p = BytesParser::from(input);
+
+        assert!(p.expect_bytes(b"\x89PNG\r\n\x1A\n").is_ok());
+        assert!(p.expect_u32(13).is_ok());
+        assert!(p.expect_bytes(b"IHDR").is_ok());
+        assert!(p.expect_u8(1).is_ok());
+        assert!(p.is_at_end());
+
+        p.reset();
+        p.set_endian(ParsingEndian::LE);
+        assert!(p.expect_u16(0x5089).is_ok());
+        assert!(p.expect_bytes(&[]).is_ok());
+        assert_eq!(p.position(), 2);
+    }
+
+    #[test]
+    fn try_expecting_unexpected_values() {
+        let input: &[u8] = &[0x7F, 0x45, 0x4C, 0x46, 0x02];
+
+        let mut p = BytesParser::from(input);
+
+        let err = p.expect_bytes(b"MZ").unwrap_err();
+        assert_eq!(
+            err,
+            BytesParserError::UnexpectedValue {
+                offset: 0,
+                expected: "[4D, 5A]".to_string(),
+                found: "[7F, 45]".to_string(),
+            }
+        );
+        assert_eq!(err.to_string(), "Unexpected value at position 0: expected [4D, 5A], found [7F, 45]");
+        assert!(p.is_at_start());
+
+        assert!(p.move_forward(4).is_ok());
+        assert_eq!(
+            p.expect_u8(1).unwrap_err(),
+            BytesParserError::UnexpectedValue {
+                offset: 4,
+                expected: "1".to_string(),
+                found: "2".to_string(),
+            }
+        );
+        assert_eq!(p.position(), 4);
+
+        assert_eq!(p.expect_u16(2).unwrap_err(), BytesParserError::NotEnoughBytesForTypeError("u16".to_string()));
+        assert_eq!(p.expect_bytes(b"\x02\x00").unwrap_err(), BytesParserError::NotEnoughBytesForSlice(2));
+    }
+}
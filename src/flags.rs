@@ -0,0 +1,132 @@
+use crate::errors::BytesParserError;
+use crate::parser::BytesParser;
+use crate::scalar::Scalar;
+
+use bitflags::Flags;
+use std::fmt::LowerHex;
+
+/// Control what to do with the bits that don't correspond to any known flag, when parsing flags.
+///
+/// See [`BytesParser::parse_flags`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum UnknownBitsPolicy {
+    /// Unknown bits are dropped.
+    Truncate,
+
+    /// Unknown bits are kept, as they are, in the returned flags.
+    Retain,
+
+    /// Unknown bits produce an error (default).
+    ///
+    /// This is the safest choice, as silently dropping unknown bits can hide, for example,
+    /// a newer version of the format that is not supported yet.
+    Reject,
+}
+
+impl Default for UnknownBitsPolicy {
+    /// Default value for [UnknownBitsPolicy] is [UnknownBitsPolicy::Reject].
+    fn default() -> Self {
+        UnknownBitsPolicy::Reject
+    }
+}
+
+impl<'a> BytesParser<'a> {
+    /// Parse flags `F`, defined with the [bitflags](https://docs.rs/bitflags) crate,
+    /// and update the internal cursor accordingly.
+    ///
+    /// The underlying bits are parsed using the [`crate::ParsingEndian`] currently in use,
+    /// then any unknown bit is handled according to the given [`UnknownBitsPolicy`].
+    /// It produces an error if unknown bits are rejected and found: in that case,
+    /// the cursor is not moved.
+    ///
+    /// Available with the `bitflags` feature.
+    ///
+    /// # Arguments
+    ///
+    /// * `policy` - What to do with the unknown bits.
+    pub fn parse_flags<F>(&mut self, policy: UnknownBitsPolicy) -> Result<F, BytesParserError>
+    where
+        F: Flags,
+        F::Bits: Scalar + LowerHex,
+    {
+        let mut p = *self;
+        let bits: F::Bits = p.parse_scalar(p.endian())?;
+
+        let flags = match policy {
+            UnknownBitsPolicy::Truncate => F::from_bits_truncate(bits),
+            UnknownBitsPolicy::Retain => F::from_bits_retain(bits),
+            UnknownBitsPolicy::Reject => F::from_bits(bits).ok_or_else(|| {
+                let unknown = bits & !F::all().bits();
+                BytesParserError::UnknownFlagsError(format!("{:#x}", unknown), self.position())
+            })?,
+        };
+
+        *self = p;
+        Ok(flags)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{BytesParser, BytesParserError, ParsingEndian, UnknownBitsPolicy};
+
+    bitflags::bitflags! {
+        #[derive(Debug, Copy, Clone, Eq, PartialEq)]
+        struct SegmentFlags: u32 {
+            const EXECUTE = 0x1;
+            const WRITE = 0x2;
+            const READ = 0x4;
+        }
+    }
+
+    #[test]
+    fn parse_flags() {
+        let input: &[u8] = &[
+            0x05, 0x00, 0x00, 0x00, //< read + execute
+            0x06, 0x00, 0x00, 0x80, //< read + write + unknown bit
+        ];
+
+        let mut p = BytesParser::from(input);
+        p.set_endian(ParsingEndian::LE);
+
+        assert_eq!(
+            p.parse_flags::<SegmentFlags>(UnknownBitsPolicy::Reject).unwrap(),
+            SegmentFlags::READ | SegmentFlags::EXECUTE
+        );
+
+        let mut pt = p;
+        assert_eq!(
+            pt.parse_flags::<SegmentFlags>(UnknownBitsPolicy::Truncate).unwrap(),
+            SegmentFlags::READ | SegmentFlags::WRITE
+        );
+
+        let retained = p.parse_flags::<SegmentFlags>(UnknownBitsPolicy::Retain).unwrap();
+        assert_eq!(retained.bits(), 0x8000_0006);
+        assert!(retained.contains(SegmentFlags::READ | SegmentFlags::WRITE));
+        assert!(p.is_at_end());
+    }
+
+    #[test]
+    fn try_parsing_unknown_flags() {
+        let input: &[u8] = &[0x00, 0x00, 0x01, 0x0C, 0x00];
+
+        let mut p = BytesParser::from(input);
+
+        assert!(p.move_forward(1).is_ok());
+        assert_eq!(
+            p.parse_flags::<SegmentFlags>(UnknownBitsPolicy::Reject).unwrap_err(),
+            BytesParserError::UnknownFlagsError("0x10c00".to_string(), 1)
+        );
+        assert_eq!(p.position(), 1);
+        assert_eq!(
+            p.parse_flags::<SegmentFlags>(UnknownBitsPolicy::default()).unwrap_err(),
+            BytesParserError::UnknownFlagsError("0x10c00".to_string(), 1)
+        );
+
+        assert!(p.move_forward(1).is_ok());
+        assert_eq!(
+            p.parse_flags::<SegmentFlags>(UnknownBitsPolicy::default()).unwrap_err(),
+            BytesParserError::NotEnoughBytesForTypeError("u32".to_string())
+        );
+    }
+}
@@ -0,0 +1,140 @@
+use crate::errors::BytesParserError;
+use crate::parser::BytesParser;
+
+use std::fmt;
+
+/// A [UUID](https://en.wikipedia.org/wiki/Universally_unique_identifier) (a.k.a. GUID), made of 16 bytes.
+///
+/// The bytes are always stored in the [RFC 4122](https://www.rfc-editor.org/rfc/rfc4122) order
+/// (i.e. Big-Endian), independently of the layout it was parsed from: this makes UUIDs parsed
+/// with [`BytesParser::parse_uuid`] and [`BytesParser::parse_guid`] comparable with each other.
+///
+/// It's displayed in its canonical, hyphenated, lowercase form
+/// (e.g. `ebd0a0a2-b9e5-4433-87c0-68b6b72699c7`).
+///
+/// With the `uuid` feature, it can be converted to and from [`uuid::Uuid`](https://docs.rs/uuid).
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct Uuid([u8; 16]);
+
+impl Uuid {
+    /// Creates a new [`Uuid`] from its 16 bytes, in RFC 4122 order.
+    pub const fn from_bytes(bytes: [u8; 16]) -> Self {
+        Uuid(bytes)
+    }
+
+    /// Returns the 16 bytes that make up this [`Uuid`], in RFC 4122 order.
+    pub const fn as_bytes(&self) -> &[u8; 16] {
+        &self.0
+    }
+}
+
+impl From<[u8; 16]> for Uuid {
+    fn from(bytes: [u8; 16]) -> Self {
+        Uuid(bytes)
+    }
+}
+
+impl From<Uuid> for [u8; 16] {
+    fn from(uuid: Uuid) -> Self {
+        uuid.0
+    }
+}
+
+#[cfg(feature = "uuid")]
+impl From<Uuid> for uuid::Uuid {
+    fn from(uuid: Uuid) -> Self {
+        uuid::Uuid::from_bytes(uuid.0)
+    }
+}
+
+#[cfg(feature = "uuid")]
+impl From<uuid::Uuid> for Uuid {
+    fn from(uuid: uuid::Uuid) -> Self {
+        Uuid(uuid.into_bytes())
+    }
+}
+
+impl fmt::Display for Uuid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, byte) in self.0.iter().enumerate() {
+            if matches!(i, 4 | 6 | 8 | 10) {
+                f.write_str("-")?;
+            }
+            write!(f, "{byte:02x}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a> BytesParser<'a> {
+    /// Parse a [`Uuid`] (i.e. 16 bytes) in the [RFC 4122](https://www.rfc-editor.org/rfc/rfc4122) layout,
+    /// and update the internal cursor accordingly.
+    ///
+    /// All the fields of the UUID are Big-Endian, regardless of the [`crate::ParsingEndian`]
+    /// currently in use.
+    pub fn parse_uuid(&mut self) -> Result<Uuid, BytesParserError> {
+        Ok(Uuid(self.parse_array_copy()?))
+    }
+
+    /// Parse a [`Uuid`] (i.e. 16 bytes) in the Microsoft GUID layout, and update the internal cursor accordingly.
+    ///
+    /// This is a mixed-endian layout, regardless of the [`crate::ParsingEndian`] currently in use:
+    /// a Little-Endian [`u32`], two Little-Endian [`u16`], then 8 bytes.
+    /// It's used, for example, by GPT partition tables and COM type libraries.
+    pub fn parse_guid(&mut self) -> Result<Uuid, BytesParserError> {
+        let mut bytes: [u8; 16] = self.parse_array_copy()?;
+        bytes[0..4].reverse();
+        bytes[4..6].reverse();
+        bytes[6..8].reverse();
+
+        Ok(Uuid(bytes))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{BytesParser, BytesParserError, Uuid};
+
+    #[test]
+    fn parse_uuid_and_guid() {
+        let input: &[u8] = &[
+            0xEB, 0xD0, 0xA0, 0xA2, 0xB9, 0xE5, 0x44, 0x33, //< RFC 4122
+            0x87, 0xC0, 0x68, 0xB6, 0xB7, 0x26, 0x99, 0xC7, //<
+            0xA2, 0xA0, 0xD0, 0xEB, 0xE5, 0xB9, 0x33, 0x44, //< Microsoft GUID
+            0x87, 0xC0, 0x68, 0xB6, 0xB7, 0x26, 0x99, 0xC7, //<
+        ];
+
+        let mut p = BytesParser::from(input);
+
+        let uuid = p.parse_uuid().unwrap();
+        assert_eq!(uuid.to_string(), "ebd0a0a2-b9e5-4433-87c0-68b6b72699c7");
+        assert_eq!(p.parse_guid().unwrap(), uuid);
+        assert_eq!(Uuid::from_bytes(input[..16].try_into().unwrap()), uuid);
+        assert!(p.is_at_end());
+    }
+
+    #[cfg(feature = "uuid")]
+    #[test]
+    fn convert_to_uuid_crate() {
+        let input: &[u8] =
+            &[0x67, 0xE5, 0x50, 0x44, 0x10, 0xB1, 0x42, 0x6F, 0x92, 0x47, 0xBB, 0x68, 0x0E, 0x5F, 0xE0, 0xC8];
+
+        let mut p = BytesParser::from(input);
+
+        let uuid: uuid::Uuid = p.parse_uuid().unwrap().into();
+        assert_eq!(uuid, uuid::Uuid::from_u128(0x67e55044_10b1_426f_9247_bb680e5fe0c8));
+        assert_eq!(Uuid::from(uuid).to_string(), uuid.to_string());
+    }
+
+    #[test]
+    fn try_parsing_truncated_uuid() {
+        let input: &[u8] = &[0x00; 15];
+
+        let mut p = BytesParser::from(input);
+
+        assert_eq!(p.parse_uuid().unwrap_err(), BytesParserError::NotEnoughBytesForTypeError("[u8; 16]".to_string()));
+        assert_eq!(p.parse_guid().unwrap_err(), BytesParserError::NotEnoughBytesForTypeError("[u8; 16]".to_string()));
+        assert!(p.is_at_start());
+    }
+}
@@ -8,18 +8,48 @@
 //!
 //! * **simplicity:** just a _thin wrapper_ around an array of bytes,
 //!   with a cursor to track progress.
-//! * **zero-copy:** all you get back is either a bit-copied primitive,
-//!   or a reference backed by the original bytes - never cloning.
-//! * **only primitive types**: if you need to serialize/deserialize complex data structures,
-//!   you probably want [serde].
+//! * **zero-copy by default:** all you get back is either a bit-copied primitive,
+//!   or a reference backed by the original bytes. The few allocating helpers are opt-in:
+//!   bulk arrays (e.g. `parse_f32_array`), `parse_vec_with` and `parse_counted` return a [`Vec`],
+//!   `parse_bcd_str` and `parse_packed_decimal` return a [`String`], while lossy and code page
+//!   string parsers (e.g. `parse_str_latin1`) return an owned [`Cow`](std::borrow::Cow) when needed.
+//! * **primitive types first**: beside primitives, only small values common to binary formats
+//!   (e.g. addresses, UUIDs, timestamps); if you need to serialize/deserialize complex
+//!   data structures, you probably want [serde].
 //!
 //! ## Features
 //!
 //! * Parse all primitive [scalar types], signed and unsigned,
-//!   as well as [`&str`] and sub-slice of `&[u8]`.
+//!   as well as [`&str`] (also lossy), sub-slice of `&[u8]` and fixed-size arrays `&[u8; N]`.
+//! * Parse [`bool`] and enums from their discriminant (e.g. `parse_enum`), as well as
+//!   [bitflags] with the optional `bitflags` feature (see `UnknownBitsPolicy`).
+//! * Bulk parsing of contiguous scalars (e.g. `parse_f32_array`), or zero-copy lazy views
+//!   over them (see [`ScalarView`]).
+//! * Parse lines, to mix text and binary parsing (see [`LineTerminator`]).
+//! * Parse network addresses, always in network order: IPv4, IPv6, socket addresses and MAC
+//!   (see [`MacAddr`]).
+//! * Parse UUIDs, both in the RFC 4122 and in the Microsoft GUID mixed-endian layouts (see [`Uuid`]),
+//!   convertible to [uuid] with the optional `uuid` feature.
+//! * Parse timestamps of common binary epochs: Unix, NTP, Windows `FILETIME`, Mac HFS
+//!   and MS-DOS (see [`DosDateTime`]).
+//! * Search for byte patterns, and cut slices delimited by them (e.g. `take_until`).
+//! * Verify expected literals, like signatures and magic numbers (e.g. `expect_bytes`, `expect_u32`).
+//! * Parse count-prefixed collections of elements, with a guard against huge counts,
+//!   and iterate over concatenated records (see [`Records`]).
 //! * Internal, auto-updating cursor, to implement a simple scanning logic.
-//! * Options to move the cursor arbitrarily, but safely, along the input slice.
-//! * Support for [Endianness] selection (see [`ParsingEndian`]).
+//! * Options to move the cursor arbitrarily, but safely, along the input slice,
+//!   also relative to its end (e.g. to parse trailers), or to align it skipping padding
+//!   (see [`AlignmentBase`]).
+//! * Parse fixed-width, padded string fields (see [`StrPadding`]).
+//! * Parse strings in legacy single-byte encodings: ASCII, Latin-1, Windows-1252 and EBCDIC
+//!   (see [`EbcdicCodePage`]), as well as Java's Modified UTF-8 and CESU-8.
+//! * Parse ASCII-encoded numbers, fixed-width or delimiter-terminated, in decimal, hexadecimal
+//!   or octal (see [`AsciiPadding`]).
+//! * Parse [BCD] and packed decimal (i.e. COBOL `COMP-3`) numbers.
+//! * Support for [Endianness] selection (see [`ParsingEndian`]), including native and
+//!   middle-endian (PDP-11 and ARM FPA), also overridable per call
+//!   (e.g. `parse_u32_le`, `parse_u32_be`, `parse_u32_ne`) or detected from magic numbers
+//!   (see [`EndianMagic`]).
 //! * Descriptive errors (see [`BytesParserError`]).
 //! * Minimal dependencies.
 //!
@@ -51,13 +81,45 @@
 //!
 //! [nom]: https://crates.io/crates/nom
 //! [serde]: https://crates.io/crates/serde
+//! [uuid]: https://crates.io/crates/uuid
+//! [BCD]: https://en.wikipedia.org/wiki/Binary-coded_decimal
+//! [bitflags]: https://crates.io/crates/bitflags
 //! [Endianness]: https://en.wikipedia.org/wiki/Endianness
 //! [scalar types]: https://doc.rust-lang.org/book/ch03-02-data-types.html#scalar-types
 
+mod alignment;
+mod array;
+mod ascii;
+mod bcd;
+mod codepage;
+mod collection;
 mod endianness;
 mod errors;
+mod expect;
+#[cfg(feature = "bitflags")]
+mod flags;
+mod guid;
+mod line;
+mod net;
 mod parser;
+mod scalar;
+mod search;
+mod text;
+mod time;
+mod view;
 
-pub use self::endianness::ParsingEndian;
+pub use self::alignment::AlignmentBase;
+pub use self::ascii::AsciiPadding;
+pub use self::collection::{Records, RecordsWithOffsets};
+pub use self::endianness::{EndianMagic, ParsingEndian, DEFAULT_ENDIAN_MAGICS};
 pub use self::errors::BytesParserError;
+#[cfg(feature = "bitflags")]
+pub use self::flags::UnknownBitsPolicy;
+pub use self::guid::Uuid;
+pub use self::line::LineTerminator;
+pub use self::net::MacAddr;
 pub use self::parser::BytesParser;
+pub use self::scalar::Scalar;
+pub use self::text::{EbcdicCodePage, StrPadding};
+pub use self::time::DosDateTime;
+pub use self::view::{ScalarView, ScalarViewIter};
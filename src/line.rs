@@ -0,0 +1,155 @@
+use crate::collection::Records;
+use crate::errors::BytesParserError;
+use crate::parser::BytesParser;
+
+use memchr::{memchr, memmem};
+use std::str;
+
+/// Control which sequence of bytes terminates a line, when parsing lines.
+///
+/// See [`BytesParser::parse_line`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum LineTerminator {
+    /// Lines are terminated by either `\n` (LF) or `\r\n` (CR LF) (default).
+    LfOrCrLf,
+
+    /// Lines are terminated by `\n` (LF) only: a preceding `\r` is part of the line.
+    Lf,
+
+    /// Lines are terminated by `\r\n` (CR LF) only: a lone `\n` is part of the line.
+    ///
+    /// This is what most text-based network protocols require (e.g. HTTP/1, SMTP, Redis RESP).
+    CrLf,
+}
+
+impl Default for LineTerminator {
+    /// Default value for [LineTerminator] is [LineTerminator::LfOrCrLf].
+    fn default() -> Self {
+        LineTerminator::LfOrCrLf
+    }
+}
+
+impl<'a> BytesParser<'a> {
+    /// "Parse" a line as a slice of bytes `&[u8]`, and update the internal cursor accordingly.
+    ///
+    /// The line starts at [`Self::position`] and ends with the [`Self::line_terminator`]:
+    /// the returned slice does not include the terminator, but the cursor is moved past it.
+    /// It produces an error if the line is longer than [`Self::max_line_length`], or if the
+    /// terminator is not found. In case of error, the cursor is not moved.
+    ///
+    /// The data returned is a "view" of the original bytes array, so that should be considered
+    /// when handling the returned reference and its lifetime.
+    pub fn parse_line(&mut self) -> Result<&'a [u8], BytesParserError> {
+        let remaining = self.remaining();
+
+        // The terminator is at most 2 bytes: no need to look further than that, past the maximum length
+        let window = &remaining[..remaining.len().min(self.max_line_length().saturating_add(2))];
+        let found = match self.line_terminator() {
+            LineTerminator::LfOrCrLf => memchr(b'\n', window).map(|i| match i {
+                i if i > 0 && window[i - 1] == b'\r' => (i - 1, 2),
+                i => (i, 1),
+            }),
+            LineTerminator::Lf => memchr(b'\n', window).map(|i| (i, 1)),
+            LineTerminator::CrLf => memmem::find(window, b"\r\n").map(|i| (i, 2)),
+        };
+
+        match found {
+            Some((length, _)) if length > self.max_line_length() => {
+                Err(BytesParserError::LineTooLongError(self.max_line_length()))
+            },
+            Some((length, terminator_length)) => {
+                let line = self.parse_slice(length)?;
+                self.parse_slice(terminator_length)?;
+
+                Ok(line)
+            },
+            None if window.len() < remaining.len() => Err(BytesParserError::LineTooLongError(self.max_line_length())),
+            None => Err(BytesParserError::PatternNotFoundError(match self.line_terminator() {
+                LineTerminator::CrLf => b"\r\n".to_vec(),
+                _ => b"\n".to_vec(),
+            })),
+        }
+    }
+
+    /// Parse a line as a UTF-8 [`&str`], and update the internal cursor accordingly.
+    ///
+    /// Like [`Self::parse_line`], but it also produces an error if the line is not valid UTF-8.
+    /// In case of error, the cursor is not moved.
+    pub fn parse_line_utf8(&mut self) -> Result<&'a str, BytesParserError> {
+        let mut p = *self;
+        let line = p.parse_line()?;
+        let line = str::from_utf8(line)
+            .map_err(|err| BytesParserError::StringParseError(err, self.absolute_position() + err.valid_up_to()))?;
+
+        *self = p;
+        Ok(line)
+    }
+
+    /// Returns an iterator of lines, calling [`Self::parse_line`] repeatedly until [`Self::is_at_end`].
+    ///
+    /// The iteration stops after the first error (e.g. the last line is not terminated).
+    /// See [`Self::iter_with`].
+    pub fn lines(&mut self) -> Records<'_, 'a, impl FnMut(&mut BytesParser<'a>) -> Result<&'a [u8], BytesParserError>> {
+        self.iter_with(|p| p.parse_line())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{BytesParser, BytesParserError, LineTerminator};
+
+    #[test]
+    fn parse_lines_and_binary() {
+        let input: &[u8] = b"HTTP/1.1 200 OK\r\nContent-Length: 2\n\r\n\x00\x2A";
+
+        let mut p = BytesParser::from(input);
+
+        assert_eq!(p.parse_line_utf8().unwrap(), "HTTP/1.1 200 OK");
+        assert_eq!(p.parse_line().unwrap(), b"Content-Length: 2");
+        assert_eq!(p.parse_line().unwrap(), b"");
+        assert_eq!(p.parse_u16().unwrap(), 42);
+        assert!(p.is_at_end());
+    }
+
+    #[test]
+    fn parse_lines_with_terminator() {
+        let input: &[u8] = b"a\nb\r\nc\r\n";
+
+        let mut p = BytesParser::from(input);
+        p.set_line_terminator(LineTerminator::CrLf);
+        assert_eq!(p.parse_line().unwrap(), b"a\nb");
+
+        p.reset();
+        p.set_line_terminator(LineTerminator::Lf);
+        let lines = p.lines().collect::<Result<Vec<_>, _>>().unwrap();
+        assert_eq!(lines, vec![&b"a"[..], &b"b\r"[..], &b"c\r"[..]]);
+
+        p.reset();
+        p.set_line_terminator(LineTerminator::LfOrCrLf);
+        let lines = p.lines().with_offsets().collect::<Result<Vec<_>, _>>().unwrap();
+        assert_eq!(lines, vec![(0, &b"a"[..]), (2, &b"b"[..]), (5, &b"c"[..])]);
+    }
+
+    #[test]
+    fn try_parsing_invalid_lines() {
+        let input: &[u8] = b"12345\r\n123\r\n\xFF\n12";
+
+        let mut p = BytesParser::from(input);
+        p.set_max_line_length(3);
+
+        assert_eq!(p.parse_line().unwrap_err(), BytesParserError::LineTooLongError(3));
+        assert!(p.move_at(7).is_ok());
+        assert_eq!(p.parse_line().unwrap(), b"123");
+        assert!(p.parse_line_utf8().is_err());
+        assert_eq!(p.position(), 12);
+        assert_eq!(p.parse_line().unwrap(), b"\xFF");
+        assert_eq!(p.parse_line().unwrap_err(), BytesParserError::PatternNotFoundError(b"\n".to_vec()));
+
+        p.reset();
+        p.set_max_line_length(usize::MAX);
+        let mut lines = p.lines();
+        assert_eq!(lines.next(), Some(Ok(&b"12345"[..])));
+        assert_eq!(lines.nth(2), Some(Err(BytesParserError::PatternNotFoundError(b"\n".to_vec()))));
+        assert_eq!(lines.next(), None);
+    }
+}
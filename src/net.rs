@@ -0,0 +1,147 @@
+use crate::endianness::ParsingEndian;
+use crate::errors::BytesParserError;
+use crate::parser::BytesParser;
+
+use std::fmt;
+use std::net::{Ipv4Addr, Ipv6Addr, SocketAddrV4, SocketAddrV6};
+
+/// A [MAC address](https://en.wikipedia.org/wiki/MAC_address), made of 6 bytes.
+///
+/// It's displayed in its canonical form of colon-separated, lowercase hexadecimal bytes
+/// (e.g. `00:1a:2b:3c:4d:5e`).
+///
+/// See [`BytesParser::parse_mac`].
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct MacAddr([u8; 6]);
+
+impl MacAddr {
+    /// Creates a new [`MacAddr`] from its 6 bytes.
+    pub const fn new(octets: [u8; 6]) -> Self {
+        MacAddr(octets)
+    }
+
+    /// Returns the 6 bytes that make up this [`MacAddr`].
+    pub const fn octets(&self) -> [u8; 6] {
+        self.0
+    }
+}
+
+impl From<[u8; 6]> for MacAddr {
+    fn from(octets: [u8; 6]) -> Self {
+        MacAddr(octets)
+    }
+}
+
+impl From<MacAddr> for [u8; 6] {
+    fn from(mac: MacAddr) -> Self {
+        mac.0
+    }
+}
+
+impl fmt::Display for MacAddr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let [a, b, c, d, e, g] = self.0;
+        write!(f, "{a:02x}:{b:02x}:{c:02x}:{d:02x}:{e:02x}:{g:02x}")
+    }
+}
+
+impl<'a> BytesParser<'a> {
+    /// Parse an [`Ipv4Addr`] (i.e. 4 bytes), and update the internal cursor accordingly.
+    ///
+    /// Addresses are always in network order (i.e. Big-Endian), regardless of the
+    /// [`ParsingEndian`] currently in use.
+    pub fn parse_ipv4(&mut self) -> Result<Ipv4Addr, BytesParserError> {
+        Ok(Ipv4Addr::from(self.parse_array_copy::<4>()?))
+    }
+
+    /// Parse an [`Ipv6Addr`] (i.e. 16 bytes), and update the internal cursor accordingly.
+    ///
+    /// Addresses are always in network order (i.e. Big-Endian), regardless of the
+    /// [`ParsingEndian`] currently in use.
+    pub fn parse_ipv6(&mut self) -> Result<Ipv6Addr, BytesParserError> {
+        Ok(Ipv6Addr::from(self.parse_array_copy::<16>()?))
+    }
+
+    /// Parse a [`SocketAddrV4`] (i.e. 4 bytes of address, followed by a [`u16`] port),
+    /// and update the internal cursor accordingly.
+    ///
+    /// Both address and port are always in network order (i.e. Big-Endian), regardless of the
+    /// [`ParsingEndian`] currently in use. In case of error, the cursor is not moved.
+    pub fn parse_socket_addr_v4(&mut self) -> Result<SocketAddrV4, BytesParserError> {
+        let mut p = *self;
+        let ip = p.parse_ipv4()?;
+        let port = p.parse_scalar(ParsingEndian::BE)?;
+
+        *self = p;
+        Ok(SocketAddrV4::new(ip, port))
+    }
+
+    /// Parse a [`SocketAddrV6`] (i.e. 16 bytes of address, followed by a [`u16`] port),
+    /// and update the internal cursor accordingly.
+    ///
+    /// Both address and port are always in network order (i.e. Big-Endian), regardless of the
+    /// [`ParsingEndian`] currently in use. Flow information and scope ID are not part of the
+    /// wire format, so they are both `0`. In case of error, the cursor is not moved.
+    pub fn parse_socket_addr_v6(&mut self) -> Result<SocketAddrV6, BytesParserError> {
+        let mut p = *self;
+        let ip = p.parse_ipv6()?;
+        let port = p.parse_scalar(ParsingEndian::BE)?;
+
+        *self = p;
+        Ok(SocketAddrV6::new(ip, port, 0, 0))
+    }
+
+    /// Parse a [`MacAddr`] (i.e. 6 bytes), and update the internal cursor accordingly.
+    pub fn parse_mac(&mut self) -> Result<MacAddr, BytesParserError> {
+        Ok(MacAddr::from(self.parse_array_copy::<6>()?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{BytesParser, BytesParserError, MacAddr, ParsingEndian};
+    use std::net::{Ipv4Addr, Ipv6Addr};
+
+    #[test]
+    fn parse_addresses() {
+        let input: &[u8] = &[
+            0xC0, 0xA8, 0x00, 0x01, //< 192.168.0.1
+            0x0A, 0x00, 0x00, 0x02, 0x1F, 0x90, //< 10.0.0.2:8080
+            0x20, 0x01, 0x0D, 0xB8, 0x00, 0x00, 0x00, 0x00, //< 2001:db8::1
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, //<
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, //< [::1]:443
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, //<
+            0x01, 0xBB, //<
+            0x00, 0x1A, 0x2B, 0x3C, 0x4D, 0x5E, //< MAC
+        ];
+
+        let mut p = BytesParser::from(input);
+        p.set_endian(ParsingEndian::LE);
+
+        assert_eq!(p.parse_ipv4().unwrap(), Ipv4Addr::new(192, 168, 0, 1));
+        assert_eq!(p.parse_socket_addr_v4().unwrap().to_string(), "10.0.0.2:8080");
+        assert_eq!(p.parse_ipv6().unwrap(), Ipv6Addr::new(0x2001, 0xDB8, 0, 0, 0, 0, 0, 1));
+        assert_eq!(p.parse_socket_addr_v6().unwrap().to_string(), "[::1]:443");
+
+        let mac = p.parse_mac().unwrap();
+        assert_eq!(mac, MacAddr::new([0x00, 0x1A, 0x2B, 0x3C, 0x4D, 0x5E]));
+        assert_eq!(mac.to_string(), "00:1a:2b:3c:4d:5e");
+        assert!(p.is_at_end());
+    }
+
+    #[test]
+    fn try_parsing_truncated_addresses() {
+        let input: &[u8] = &[0x7F, 0x00, 0x00, 0x01, 0x00];
+
+        let mut p = BytesParser::from(input);
+
+        assert_eq!(
+            p.parse_socket_addr_v4().unwrap_err(),
+            BytesParserError::NotEnoughBytesForTypeError("u16".to_string())
+        );
+        assert!(p.is_at_start());
+        assert_eq!(p.parse_ipv6().unwrap_err(), BytesParserError::NotEnoughBytesForTypeError("[u8; 16]".to_string()));
+        assert_eq!(p.parse_mac().unwrap_err(), BytesParserError::NotEnoughBytesForTypeError("[u8; 6]".to_string()));
+        assert_eq!(p.parse_ipv4().unwrap(), Ipv4Addr::LOCALHOST);
+    }
+}
@@ -1,8 +1,12 @@
-use crate::endianness::ParsingEndian;
+use crate::alignment::AlignmentBase;
+use crate::endianness::{EndianMagic, ParsingEndian};
 use crate::errors::BytesParserError;
+use crate::line::LineTerminator;
+use crate::scalar::Scalar;
 
-use std::convert::TryInto;
-use std::mem;
+use std::borrow::Cow;
+use std::convert::{TryFrom, TryInto};
+use std::fmt::Display;
 use std::str;
 
 /// A zero-copy bytes parser, useful when parsing bespoke binary protocols.
@@ -26,7 +30,12 @@ pub struct BytesParser<'a> {
     buffer: &'a [u8],
     length: usize,
     cursor: usize,
+    offset: usize,
     endian: ParsingEndian,
+    max_count: usize,
+    alignment_base: AlignmentBase,
+    line_terminator: LineTerminator,
+    max_line_length: usize,
 }
 
 impl<'a> From<&'a [u8]> for BytesParser<'a> {
@@ -35,13 +44,18 @@ impl<'a> From<&'a [u8]> for BytesParser<'a> {
             buffer: bytes,
             length: bytes.len(),
             cursor: 0,
+            offset: 0,
             endian: ParsingEndian::default(),
+            max_count: usize::MAX,
+            alignment_base: AlignmentBase::default(),
+            line_terminator: LineTerminator::default(),
+            max_line_length: usize::MAX,
         }
     }
 }
 
 macro_rules! build_parse_type_fn {
-    ($fn_name:ident, $parsed_type:ty) => {
+    ($fn_name:ident, $fn_name_le:ident, $fn_name_be:ident, $fn_name_ne:ident, $parsed_type:ty) => {
         #[doc = "Parse a`"]
         #[doc=stringify!($parsed_type)]
         #[doc = "` and update the internal cursor accordingly.\n\n"]
@@ -50,48 +64,80 @@ macro_rules! build_parse_type_fn {
         #[doc=stringify!($parsed_type)]
         #[doc = "`."]
         pub fn $fn_name(&mut self) -> Result<$parsed_type, BytesParserError> {
-            let size = mem::size_of::<$parsed_type>();
-            if self.parseable() < size {
-                return Err(BytesParserError::NotEnoughBytesForTypeError(stringify!($parsed_type).to_string()));
-            }
-
-            let start = self.cursor;
-            let end = self.cursor + size;
-            let slice = self.buffer[start..end].try_into().unwrap();
+            self.parse_scalar(self.endian)
+        }
 
-            let value = match self.endian {
-                ParsingEndian::BE => <$parsed_type>::from_be_bytes(slice),
-                ParsingEndian::LE => <$parsed_type>::from_le_bytes(slice),
-            };
+        #[doc = "Parse a`"]
+        #[doc=stringify!($parsed_type)]
+        #[doc = "` using [`ParsingEndian::LE`], regardless of [`BytesParser::endian`].\n\n"]
+        #[doc = "See [`BytesParser::"]
+        #[doc=stringify!($fn_name)]
+        #[doc = "`]."]
+        pub fn $fn_name_le(&mut self) -> Result<$parsed_type, BytesParserError> {
+            self.parse_scalar(ParsingEndian::LE)
+        }
 
-            self.cursor += size;
+        #[doc = "Parse a`"]
+        #[doc=stringify!($parsed_type)]
+        #[doc = "` using [`ParsingEndian::BE`], regardless of [`BytesParser::endian`].\n\n"]
+        #[doc = "See [`BytesParser::"]
+        #[doc=stringify!($fn_name)]
+        #[doc = "`]."]
+        pub fn $fn_name_be(&mut self) -> Result<$parsed_type, BytesParserError> {
+            self.parse_scalar(ParsingEndian::BE)
+        }
 
-            Ok(value)
+        #[doc = "Parse a`"]
+        #[doc=stringify!($parsed_type)]
+        #[doc = "` using [`ParsingEndian::Native`], regardless of [`BytesParser::endian`].\n\n"]
+        #[doc = "See [`BytesParser::"]
+        #[doc=stringify!($fn_name)]
+        #[doc = "`]."]
+        pub fn $fn_name_ne(&mut self) -> Result<$parsed_type, BytesParserError> {
+            self.parse_scalar(ParsingEndian::Native)
         }
     };
 }
 
 impl<'a> BytesParser<'a> {
-    build_parse_type_fn!(parse_i8, i8);
-    build_parse_type_fn!(parse_u8, u8);
+    build_parse_type_fn!(parse_i8, parse_i8_le, parse_i8_be, parse_i8_ne, i8);
+    build_parse_type_fn!(parse_u8, parse_u8_le, parse_u8_be, parse_u8_ne, u8);
+
+    build_parse_type_fn!(parse_i16, parse_i16_le, parse_i16_be, parse_i16_ne, i16);
+    build_parse_type_fn!(parse_u16, parse_u16_le, parse_u16_be, parse_u16_ne, u16);
+
+    build_parse_type_fn!(parse_i32, parse_i32_le, parse_i32_be, parse_i32_ne, i32);
+    build_parse_type_fn!(parse_u32, parse_u32_le, parse_u32_be, parse_u32_ne, u32);
+
+    build_parse_type_fn!(parse_i64, parse_i64_le, parse_i64_be, parse_i64_ne, i64);
+    build_parse_type_fn!(parse_u64, parse_u64_le, parse_u64_be, parse_u64_ne, u64);
 
-    build_parse_type_fn!(parse_i16, i16);
-    build_parse_type_fn!(parse_u16, u16);
+    build_parse_type_fn!(parse_i128, parse_i128_le, parse_i128_be, parse_i128_ne, i128);
+    build_parse_type_fn!(parse_u128, parse_u128_le, parse_u128_be, parse_u128_ne, u128);
 
-    build_parse_type_fn!(parse_i32, i32);
-    build_parse_type_fn!(parse_u32, u32);
+    build_parse_type_fn!(parse_f32, parse_f32_le, parse_f32_be, parse_f32_ne, f32);
+    build_parse_type_fn!(parse_f64, parse_f64_le, parse_f64_be, parse_f64_ne, f64);
 
-    build_parse_type_fn!(parse_i64, i64);
-    build_parse_type_fn!(parse_u64, u64);
+    build_parse_type_fn!(parse_isize, parse_isize_le, parse_isize_be, parse_isize_ne, isize);
+    build_parse_type_fn!(parse_usize, parse_usize_le, parse_usize_be, parse_usize_ne, usize);
 
-    build_parse_type_fn!(parse_i128, i128);
-    build_parse_type_fn!(parse_u128, u128);
+    /// Parse a scalar type `T` using the given [`ParsingEndian`], and update the internal cursor accordingly.
+    ///
+    /// It produces an error if [`BytesParser::parseable`] returns an amount inferior to
+    /// the amount of bytes occupied by `T`.
+    pub(crate) fn parse_scalar<T: Scalar>(&mut self, endian: ParsingEndian) -> Result<T, BytesParserError> {
+        if self.parseable() < T::SIZE {
+            return Err(BytesParserError::NotEnoughBytesForTypeError(T::NAME.to_string()));
+        }
+
+        let start = self.cursor;
+        let end = self.cursor + T::SIZE;
+        let value = T::from_endian_bytes(&self.buffer[start..end], endian);
 
-    build_parse_type_fn!(parse_f32, f32);
-    build_parse_type_fn!(parse_f64, f64);
+        self.cursor += T::SIZE;
 
-    build_parse_type_fn!(parse_isize, isize);
-    build_parse_type_fn!(parse_usize, usize);
+        Ok(value)
+    }
 
     /// Parse a [`&str`] and update the internal cursor accordingly.
     ///
@@ -115,7 +161,7 @@ impl<'a> BytesParser<'a> {
     ///   [Rust Programming Language book](https://doc.rust-lang.org/book/ch08-02-strings.html#internal-representation).
     ///   Because of this, determining how many bytes to consume to parse the [`String`] is left
     ///   to the user.
-    pub fn parse_str_utf8(&mut self, size: usize) -> Result<&str, BytesParserError> {
+    pub fn parse_str_utf8(&mut self, size: usize) -> Result<&'a str, BytesParserError> {
         if self.parseable() < size {
             return Err(BytesParserError::NotEnoughBytesForStringError(size));
         }
@@ -129,8 +175,44 @@ impl<'a> BytesParser<'a> {
                 self.cursor += size;
                 Ok(result)
             },
-            Err(err) => Err(BytesParserError::StringParseError(err)),
+            Err(err) => Err(BytesParserError::StringParseError(err, self.absolute_position() + err.valid_up_to())),
+        }
+    }
+
+    /// Parse a [`&str`] and update the internal cursor accordingly, even if the string is invalid.
+    ///
+    /// Like [`Self::parse_str_utf8`], but if the bytes are not valid UTF-8, the cursor is still
+    /// moved past them, before producing the error: this allows to continue parsing whatever
+    /// follows a damaged string.
+    ///
+    /// # Arguments
+    ///
+    /// * `size` - Size of the UTF-8 [`&str`] to parse, in bytes.
+    pub fn parse_str_utf8_or_skip(&mut self, size: usize) -> Result<&'a str, BytesParserError> {
+        let result = self.parse_str_utf8(size);
+        if let Err(BytesParserError::StringParseError(..)) = result {
+            self.cursor += size;
+        }
+
+        result
+    }
+
+    /// Parse a UTF-8 string, replacing invalid UTF-8 sequences, and update the internal cursor accordingly.
+    ///
+    /// Like [`Self::parse_str_utf8`], but invalid UTF-8 sequences are replaced with
+    /// [`char::REPLACEMENT_CHARACTER`] instead of producing an error: the string is borrowed from
+    /// the original bytes array, unless a replacement was necessary.
+    /// It produces an error only if [`BytesParser::parseable`] returns an amount inferior to the given `size`.
+    ///
+    /// # Arguments
+    ///
+    /// * `size` - Size of the UTF-8 string to parse, in bytes.
+    pub fn parse_str_utf8_lossy(&mut self, size: usize) -> Result<Cow<'a, str>, BytesParserError> {
+        if self.parseable() < size {
+            return Err(BytesParserError::NotEnoughBytesForStringError(size));
         }
+
+        Ok(String::from_utf8_lossy(self.parse_slice(size)?))
     }
 
     /// Parse a single [`char`] from a [`u32`] (i.e. 4 bytes).s
@@ -143,6 +225,48 @@ impl<'a> BytesParser<'a> {
         Ok(result)
     }
 
+    /// Parse a single [`bool`] from a [`u8`] (i.e. 1 byte), and update the internal cursor accordingly.
+    ///
+    /// This is strict: `0` is [`false`] and `1` is [`true`]. It produces an error for any other value:
+    /// in that case, the cursor is not moved. See [`Self::parse_bool_lenient`] for a lenient alternative.
+    pub fn parse_bool(&mut self) -> Result<bool, BytesParserError> {
+        let mut p = *self;
+        let result = match p.parse_u8()? {
+            0 => false,
+            1 => true,
+            value => return Err(BytesParserError::InvalidBoolError(value, self.position())),
+        };
+
+        *self = p;
+        Ok(result)
+    }
+
+    /// Parse a single [`bool`] from a [`u8`] (i.e. 1 byte), and update the internal cursor accordingly.
+    ///
+    /// This is lenient: `0` is [`false`] and any other value is [`true`].
+    pub fn parse_bool_lenient(&mut self) -> Result<bool, BytesParserError> {
+        Ok(self.parse_u8()? != 0)
+    }
+
+    /// Parse an enum `E` from its discriminant of scalar type `R`, and update the internal cursor accordingly.
+    ///
+    /// The discriminant is parsed using the [`ParsingEndian`] currently in use, and then converted
+    /// into `E` via its [`TryFrom<R>`] implementation. It produces an error if the conversion fails
+    /// (i.e. unknown discriminant): in that case, the cursor is not moved.
+    pub fn parse_enum<E, R>(&mut self) -> Result<E, BytesParserError>
+    where
+        E: TryFrom<R>,
+        R: Scalar + Display,
+    {
+        let mut p = *self;
+        let discriminant: R = p.parse_scalar(p.endian())?;
+        let result = E::try_from(discriminant)
+            .map_err(|_| BytesParserError::UnknownDiscriminantError(discriminant.to_string(), self.position()))?;
+
+        *self = p;
+        Ok(result)
+    }
+
     /// "Parse" a slice of bytes `&[u8]` of given `size`, starting from [`Self::position`].
     ///
     /// This doesn't actually create anything: it cuts a slice from the inner bytes array,
@@ -156,7 +280,7 @@ impl<'a> BytesParser<'a> {
     ///
     /// * `size` - The size of the new slice to cut. The slice will be cut starting from the
     ///   current position of the internal cursor (i.e. [`Self::position`]).
-    pub fn parse_slice(&mut self, size: usize) -> Result<&[u8], BytesParserError> {
+    pub fn parse_slice(&mut self, size: usize) -> Result<&'a [u8], BytesParserError> {
         if self.parseable() < size {
             return Err(BytesParserError::NotEnoughBytesForSlice(size));
         }
@@ -166,21 +290,89 @@ impl<'a> BytesParser<'a> {
 
         self.cursor += size;
 
+        Ok(&self.buffer[start..end])
+    }
+
+    /// "Parse" a fixed-size array of bytes `&[u8; N]`, starting from [`Self::position`].
+    ///
+    /// Like [`Self::parse_slice`], but the size is part of the returned type: this is useful
+    /// for fixed-size fields, like hashes, MAC addresses or magic numbers.
+    /// It produces an error if [`BytesParser::parseable`] returns an amount inferior to `N`.
+    ///
+    /// The data returned is a "view" of the original bytes array: see [`Self::parse_array_copy`]
+    /// to get a copy instead.
+    pub fn parse_array<const N: usize>(&mut self) -> Result<&'a [u8; N], BytesParserError> {
+        if self.parseable() < N {
+            return Err(BytesParserError::NotEnoughBytesForTypeError(format!("[u8; {N}]")));
+        }
+
+        let start = self.cursor;
+        let end = self.cursor + N;
+
+        self.cursor += N;
+
         Ok(self.buffer[start..end].try_into().unwrap())
     }
 
+    /// Parse a fixed-size array of bytes `[u8; N]`, starting from [`Self::position`].
+    ///
+    /// Like [`Self::parse_array`], but it returns a copy of the bytes.
+    pub fn parse_array_copy<const N: usize>(&mut self) -> Result<[u8; N], BytesParserError> {
+        self.parse_array().copied()
+    }
+
     /// Creates a new [`BytesParser`] that is set to use a slice of bytes as its inner byte array.
     ///
     /// This uses [`Self::parse_slice`] to cut a `&[u8]`, and then initializes
     /// a new [`BytesParser`] using [`Self::from`].
     ///
+    /// The new [`BytesParser`] inherits the settings of this one, like the [`ParsingEndian`]
+    /// (see [`Self::endian`]), the maximum count (see [`Self::max_count`]) and
+    /// the alignment base (see [`Self::alignment_base`]).
+    ///
     /// # Arguments
     ///
     /// * `size` - The size of the new slice to cut and wrap inside a [`BytesParser`].
-    pub fn from_slice(&mut self, size: usize) -> Result<BytesParser, BytesParserError> {
+    pub fn from_slice(&mut self, size: usize) -> Result<BytesParser<'a>, BytesParserError> {
+        let offset = self.absolute_position();
         let slice = self.parse_slice(size)?;
 
-        Ok(BytesParser::from(slice))
+        Ok(BytesParser {
+            buffer: slice,
+            length: slice.len(),
+            cursor: 0,
+            offset,
+            ..*self
+        })
+    }
+
+    /// Creates a new [`BytesParser`] that is set to use the last `size` bytes as its inner byte array.
+    ///
+    /// This is useful to parse trailers, located relative to the end of the bytes array
+    /// (e.g. the ZIP end-of-central-directory record, ID3v1 tags, or footer checksums).
+    /// Differently from [`Self::from_slice`], the cursor of this [`BytesParser`] is not moved.
+    /// It produces an error if [`BytesParser::length`] is inferior to `size`.
+    ///
+    /// The new [`BytesParser`] inherits the settings of this one (see [`Self::from_slice`]).
+    ///
+    /// # Arguments
+    ///
+    /// * `size` - The amount of bytes, at the end of the bytes array, to wrap inside a [`BytesParser`].
+    pub fn from_end(&self, size: usize) -> Result<BytesParser<'a>, BytesParserError> {
+        if self.length < size {
+            return Err(BytesParserError::NotEnoughBytesForSlice(size));
+        }
+
+        let start = self.length - size;
+        let slice = &self.buffer[start..];
+
+        Ok(BytesParser {
+            buffer: slice,
+            length: slice.len(),
+            cursor: 0,
+            offset: self.offset + start,
+            ..*self
+        })
     }
 
     /// Length of the internal bytes array.
@@ -200,6 +392,15 @@ impl<'a> BytesParser<'a> {
         self.cursor
     }
 
+    /// Returns the 0-based position of the cursor, relative to the original bytes array.
+    ///
+    /// This differs from [`Self::position`] only for a [`BytesParser`] created with
+    /// [`Self::from_slice`] or [`Self::from_end`]: in that case, the position is relative to the
+    /// bytes array of the very first [`BytesParser`] the slice was cut from.
+    pub const fn absolute_position(&self) -> usize {
+        self.offset + self.cursor
+    }
+
     /// Returns [`true`] if the internal cursor points at the very start of the bytes array.
     ///
     /// When first created, this will return [`true`].
@@ -224,6 +425,13 @@ impl<'a> BytesParser<'a> {
         self.length - self.cursor
     }
 
+    /// Returns the bytes that can still be parsed, without moving the cursor.
+    ///
+    /// The data returned is a "view" of the original bytes array, of size [`BytesParser::parseable`].
+    pub fn remaining(&self) -> &'a [u8] {
+        &self.buffer[self.cursor..]
+    }
+
     /// Reset cursor to the very start of the bytes array.
     ///
     /// This can be used to re-parse bytes.
@@ -293,6 +501,31 @@ impl<'a> BytesParser<'a> {
         }
     }
 
+    /// Move internal cursor at `amount` bytes before the end of the internal bytes array.
+    ///
+    /// The new cursor position corresponds to the next byte that would be parsed: this is
+    /// useful to parse trailers, that are located relative to [`BytesParser::length`].
+    /// Differently from [`Self::move_at`], an `amount` of `0` is accepted, and moves the
+    /// cursor at the very end (i.e. [`Self::is_at_end`] will return [`true`]).
+    /// It produces an error if `amount` is greater than [`BytesParser::length`].
+    ///
+    /// # Arguments
+    ///
+    /// * `amount` - Amount of bytes, from the end of the bytes array, where to move the cursor at.
+    pub fn move_from_end(&mut self, amount: usize) -> Result<(), BytesParserError> {
+        if amount > self.length {
+            let amount = isize::try_from(amount).unwrap_or(isize::MAX);
+            Err(BytesParserError::CursorOutOfBoundError(
+                (self.length as isize).saturating_sub(amount),
+                self.length,
+                self.cursor,
+            ))
+        } else {
+            self.cursor = self.length - amount;
+            Ok(())
+        }
+    }
+
     /// Sets the [ParsingEndian] to be used when parsing scalar types from the internal bytes array.
     ///
     /// # Arguments
@@ -306,12 +539,121 @@ impl<'a> BytesParser<'a> {
     pub const fn endian(&self) -> ParsingEndian {
         self.endian
     }
+
+    /// Detects and sets the [ParsingEndian] to use, by looking for magic patterns in the bytes
+    /// starting at [`Self::position`].
+    ///
+    /// The given [EndianMagic] patterns are tried in order, and the first one found determines
+    /// the [ParsingEndian] to set. If none is found, the [ParsingEndian] is left unchanged and
+    /// [`None`] is returned. The cursor is never moved.
+    ///
+    /// # Arguments
+    ///
+    /// * `magics` - The [EndianMagic] patterns to look for
+    ///   (e.g. [`crate::DEFAULT_ENDIAN_MAGICS`]).
+    pub fn detect_endian(&mut self, magics: &[EndianMagic]) -> Option<ParsingEndian> {
+        let bytes = self.remaining();
+        let endian = magics.iter().find(|magic| magic.matches(bytes))?.endian;
+        self.endian = endian;
+
+        Some(endian)
+    }
+
+    /// Runs the given closure using a temporary [ParsingEndian], then restores the previous one.
+    ///
+    /// This is useful when parsing a section of the bytes array that uses a different endian
+    /// from the rest, without having to remember to call [`Self::set_endian`] again afterwards.
+    /// The previous [ParsingEndian] is restored even if the closure changes it.
+    ///
+    /// # Arguments
+    ///
+    /// * `endian` - The [ParsingEndian] to use while running `f`.
+    /// * `f` - The closure to run: it receives this [`BytesParser`], and its result is returned.
+    pub fn with_endian<T, F>(&mut self, endian: ParsingEndian, f: F) -> T
+    where
+        F: FnOnce(&mut Self) -> T,
+    {
+        let previous = self.endian;
+        self.endian = endian;
+        let result = f(self);
+        self.endian = previous;
+
+        result
+    }
+
+    /// Sets the maximum count of elements accepted when parsing collections.
+    ///
+    /// This guards methods like [`Self::parse_vec_with`] and [`Self::parse_counted`] against
+    /// huge counts (e.g. attacker-controlled), that would otherwise lead to huge allocations
+    /// or very long parsing loops. By default there is no maximum (i.e. [`usize::MAX`]).
+    ///
+    /// # Arguments
+    ///
+    /// * `max_count` - The maximum count of elements accepted.
+    pub fn set_max_count(&mut self, max_count: usize) {
+        self.max_count = max_count;
+    }
+
+    /// Return the maximum count of elements accepted when parsing collections.
+    pub const fn max_count(&self) -> usize {
+        self.max_count
+    }
+
+    /// Sets the [AlignmentBase] to be used when aligning the cursor (e.g. [`Self::align_to`]).
+    ///
+    /// # Arguments
+    ///
+    /// * `alignment_base` - The [AlignmentBase] to use when computing padding.
+    pub fn set_alignment_base(&mut self, alignment_base: AlignmentBase) {
+        self.alignment_base = alignment_base;
+    }
+
+    /// Return the [AlignmentBase] currently used.
+    pub const fn alignment_base(&self) -> AlignmentBase {
+        self.alignment_base
+    }
+
+    /// Sets the [LineTerminator] to be used when parsing lines (e.g. [`Self::parse_line`]).
+    ///
+    /// # Arguments
+    ///
+    /// * `line_terminator` - The [LineTerminator] that ends a line.
+    pub fn set_line_terminator(&mut self, line_terminator: LineTerminator) {
+        self.line_terminator = line_terminator;
+    }
+
+    /// Return the [LineTerminator] currently used.
+    pub const fn line_terminator(&self) -> LineTerminator {
+        self.line_terminator
+    }
+
+    /// Sets the maximum length of a line, in bytes and excluding the terminator,
+    /// accepted when parsing lines (e.g. [`Self::parse_line`]).
+    ///
+    /// This guards against unterminated or malicious input, that would otherwise lead to scanning
+    /// the whole bytes array looking for a terminator. By default there is no maximum
+    /// (i.e. [`usize::MAX`]).
+    ///
+    /// # Arguments
+    ///
+    /// * `max_line_length` - The maximum length of a line accepted.
+    pub fn set_max_line_length(&mut self, max_line_length: usize) {
+        self.max_line_length = max_line_length;
+    }
+
+    /// Return the maximum length of a line accepted when parsing lines.
+    pub const fn max_line_length(&self) -> usize {
+        self.max_line_length
+    }
 }
 
 #[cfg(test)]
+#[allow(clippy::bool_assert_comparison)]
 mod tests {
     use super::BytesParser;
-    use crate::{BytesParserError, ParsingEndian};
+    use crate::{BytesParserError, EndianMagic, ParsingEndian, StrPadding, DEFAULT_ENDIAN_MAGICS};
+    use std::borrow::Cow;
+    use std::convert::TryFrom;
     use std::error::Error;
 
     #[test]
@@ -444,6 +786,89 @@ mod tests {
         assert_eq!(p.is_at_end(), true);
     }
 
+    #[test]
+    fn parse_scalars_using_native_endian() {
+        let input: &[u8] = &[0x12, 0x34, 0x56, 0x78, 0x9A, 0xBC, 0xDE, 0xF0];
+
+        let mut p = BytesParser::from(input);
+        p.set_endian(ParsingEndian::Native);
+
+        assert_eq!(p.parse_u16().unwrap(), u16::from_ne_bytes([0x12, 0x34]));
+        assert_eq!(p.parse_i16().unwrap(), i16::from_ne_bytes([0x56, 0x78]));
+        assert_eq!(p.parse_f32().unwrap(), f32::from_ne_bytes([0x9A, 0xBC, 0xDE, 0xF0]));
+        assert!(p.is_at_end());
+    }
+
+    #[test]
+    fn parse_scalars_using_pdp_endian() {
+        let input: &[u8] = &[
+            0x12, //< u8
+            0x34, 0x12, //< u16
+            0x34, 0x12, 0x78, 0x56, //< u32
+            0x34, 0x12, 0x78, 0x56, 0xBC, 0x9A, 0xF0, 0xDE, //< u64
+            0x00, 0xC0, 0x00, 0x00, //< f32
+        ];
+
+        let mut p = BytesParser::from(input);
+        p.set_endian(ParsingEndian::PDP);
+
+        assert_eq!(p.parse_u8().unwrap(), 0x12);
+        assert_eq!(p.parse_u16().unwrap(), 0x1234);
+        assert_eq!(p.parse_u32().unwrap(), 0x12345678);
+        assert_eq!(p.parse_u64().unwrap(), 0x123456789ABCDEF0);
+        assert_eq!(p.parse_f32().unwrap(), -2.0);
+        assert!(p.is_at_end());
+    }
+
+    #[test]
+    fn parse_scalars_using_fpa_endian() {
+        let input: &[u8] = &[
+            0x00, 0x00, 0xF0, 0x3F, 0x00, 0x00, 0x00, 0x00, //< f64: 1.0
+            0x00, 0x00, 0x04, 0xC0, 0x00, 0x00, 0x00, 0x00, //< f64: -2.5
+            0x78, 0x56, 0x34, 0x12, 0xF0, 0xDE, 0xBC, 0x9A, //< u64
+            0x34, 0x12, //< u16
+            0x00, 0x00, 0x80, 0x3F, //< f32: 1.0
+        ];
+
+        let mut p = BytesParser::from(input);
+        p.set_endian(ParsingEndian::FPA);
+
+        assert_eq!(p.parse_f64().unwrap(), 1.0);
+        assert_eq!(p.parse_f64().unwrap(), -2.5);
+        assert_eq!(p.parse_u64().unwrap(), 0x123456789ABCDEF0);
+        assert_eq!(p.parse_u16().unwrap(), 0x1234);
+        assert_eq!(p.parse_f32().unwrap(), 1.0);
+        assert!(p.is_at_end());
+
+        p.reset();
+        assert_eq!(p.parse_f64_array(2).unwrap(), vec![1.0, -2.5]);
+    }
+
+    #[test]
+    fn parse_scalars_overriding_endian() {
+        let input: &[u8] = &[
+            0x12, 0x34, 0x56, 0x78, //< u32, big-endian
+            0x78, 0x56, 0x34, 0x12, //< u32, little-endian
+            0x12, 0x34, //< i16, big-endian
+            0x34, 0x12, //< i16, little-endian
+        ];
+
+        let mut p = BytesParser::from(input);
+        p.set_endian(ParsingEndian::LE);
+
+        assert_eq!(p.parse_u32_be().unwrap(), 0x12345678);
+        assert_eq!(p.parse_u32().unwrap(), 0x12345678);
+        p.set_endian(ParsingEndian::BE);
+        assert_eq!(p.parse_i16().unwrap(), 0x1234);
+        assert_eq!(p.parse_i16_le().unwrap(), 0x1234);
+        assert!(p.is_at_end());
+        assert_eq!(p.endian(), ParsingEndian::BE);
+
+        p.reset();
+        assert_eq!(p.parse_u32_ne().unwrap(), u32::from_ne_bytes([0x12, 0x34, 0x56, 0x78]));
+        assert_eq!(p.parse_u128_le().unwrap_err(), BytesParserError::NotEnoughBytesForTypeError("u128".to_string()));
+    }
+
     #[test]
     fn parse_moving_the_cursor_around() {
         let input: &[u8] = &[
@@ -484,6 +909,34 @@ mod tests {
         assert_eq!(p.parse_u8().unwrap(), 0x12);
     }
 
+    #[test]
+    fn parse_from_end() {
+        let input: &[u8] = &[
+            0x00, 0x01, 0x02, 0x03, //< payload
+            0x50, 0x4B, 0x05, 0x06, //< trailer signature
+            0x00, 0x04, //< trailer u16
+        ];
+
+        let mut p = BytesParser::from(input);
+        assert!(p.move_forward(2).is_ok());
+
+        let mut trailer = p.from_end(6).unwrap();
+        assert_eq!(trailer.parse_u32().unwrap(), 0x504B0506);
+        assert_eq!(trailer.parse_u16().unwrap(), 4);
+        assert!(trailer.is_at_end());
+        assert_eq!(p.position(), 2);
+
+        assert!(p.move_from_end(2).is_ok());
+        assert_eq!(p.position(), 8);
+        assert_eq!(p.parse_u16().unwrap(), 4);
+
+        assert!(p.move_from_end(0).is_ok());
+        assert!(p.is_at_end());
+
+        assert!(p.move_from_end(10).is_ok());
+        assert!(p.is_at_start());
+    }
+
     #[test]
     fn parse_string() {
         let input: &[u8] = &[
@@ -542,6 +995,30 @@ mod tests {
         assert_eq!(p.parse_u32().unwrap(), 0x12345678);
     }
 
+    #[test]
+    fn parse_array() {
+        let input: &[u8] = &[
+            0x89, 0x50, 0x4E, 0x47, //< magic number
+            0x00, 0x11, 0x22, 0x33, 0x44, 0x55, //< MAC address
+        ];
+
+        let mut p = BytesParser::from(input);
+
+        let magic: &[u8; 4] = p.parse_array().unwrap();
+        assert_eq!(magic, b"\x89PNG");
+
+        let mac = p.parse_array_copy::<6>().unwrap();
+        assert_eq!(mac, [0x00, 0x11, 0x22, 0x33, 0x44, 0x55]);
+        assert!(p.is_at_end());
+
+        p.reset();
+        assert_eq!(
+            p.parse_array::<11>().unwrap_err(),
+            BytesParserError::NotEnoughBytesForTypeError("[u8; 11]".to_string())
+        );
+        assert!(p.is_at_start());
+    }
+
     #[test]
     fn from_slice() {
         let input: &[u8] = &[
@@ -565,6 +1042,120 @@ mod tests {
         assert_eq!(p.parse_u32().unwrap(), 0x12345678);
     }
 
+    #[test]
+    fn absolute_position() {
+        let input: &[u8] = &[
+            0x12, //< u8
+            0x12, 0x34, //< u16
+            0x12, 0x34, 0x56, 0x78, //< u32
+            0x12, 0x34, 0x56, 0x78, 0x9A, 0xBC, 0xDE, 0xF0, //< u64
+        ];
+
+        let mut p = BytesParser::from(input);
+        assert!(p.move_forward(3).is_ok());
+
+        let mut ps = p.from_slice(4).unwrap();
+        assert_eq!(ps.absolute_position(), 3);
+        assert_eq!(ps.parse_u16().unwrap(), 0x1234);
+        assert_eq!(ps.position(), 2);
+        assert_eq!(ps.absolute_position(), 5);
+
+        // Nested sub-parsers keep adding up their offsets
+        let pss = ps.from_end(1).unwrap();
+        assert_eq!(pss.position(), 0);
+        assert_eq!(pss.absolute_position(), 6);
+
+        let pe = p.from_end(4).unwrap();
+        assert_eq!(pe.absolute_position(), 11);
+    }
+
+    #[test]
+    fn from_slice_inherits_endian() {
+        let input: &[u8] = &[
+            0x34, 0x12, //< u16, little-endian
+            0x78, 0x56, 0x34, 0x12, //< u32, little-endian
+        ];
+
+        let mut p = BytesParser::from(input);
+        p.set_endian(ParsingEndian::LE);
+
+        let mut ps = p.from_slice(2).unwrap();
+        assert_eq!(ps.endian(), ParsingEndian::LE);
+        assert_eq!(ps.parse_u16().unwrap(), 0x1234);
+
+        p.set_endian(ParsingEndian::BE);
+        let mut ps = p.from_slice(4).unwrap();
+        assert_eq!(ps.endian(), ParsingEndian::BE);
+        assert_eq!(ps.parse_u32().unwrap(), 0x78563412);
+    }
+
+    #[test]
+    fn parse_with_endian() {
+        let input: &[u8] = &[
+            0x12, 0x34, //< u16, big-endian
+            0x78, 0x56, 0x34, 0x12, //< u32, little-endian
+            0x12, 0x34, //< u16, big-endian
+        ];
+
+        let mut p = BytesParser::from(input);
+
+        assert_eq!(p.parse_u16().unwrap(), 0x1234);
+        let value = p.with_endian(ParsingEndian::LE, |p| {
+            assert_eq!(p.endian(), ParsingEndian::LE);
+            p.parse_u32()
+        });
+        assert_eq!(value.unwrap(), 0x12345678);
+        assert_eq!(p.endian(), ParsingEndian::BE);
+        assert_eq!(p.parse_u16().unwrap(), 0x1234);
+
+        // Nested contexts restore their own previous endian
+        p.reset();
+        p.with_endian(ParsingEndian::LE, |p| {
+            p.with_endian(ParsingEndian::BE, |p| assert_eq!(p.parse_u16().unwrap(), 0x1234));
+            assert_eq!(p.endian(), ParsingEndian::LE);
+            assert_eq!(p.parse_u32().unwrap(), 0x12345678);
+        });
+        assert_eq!(p.endian(), ParsingEndian::BE);
+    }
+
+    #[test]
+    fn detect_endian() {
+        let tiff: &[u8] = &[0x49, 0x49, 0x2A, 0x00, 0x08, 0x00, 0x00, 0x00];
+        let mut p = BytesParser::from(tiff);
+        assert_eq!(p.detect_endian(DEFAULT_ENDIAN_MAGICS), Some(ParsingEndian::LE));
+        assert_eq!(p.position(), 0);
+        assert!(p.move_forward(4).is_ok());
+        assert_eq!(p.parse_u32().unwrap(), 8);
+
+        let pcap: &[u8] = &[0xA1, 0xB2, 0xC3, 0xD4, 0x00, 0x02];
+        let mut p = BytesParser::from(pcap);
+        p.set_endian(ParsingEndian::LE);
+        assert_eq!(p.detect_endian(DEFAULT_ENDIAN_MAGICS), Some(ParsingEndian::BE));
+        assert_eq!(p.endian(), ParsingEndian::BE);
+
+        let pcapng: &[u8] = &[0x0A, 0x0D, 0x0D, 0x0A, 0x1C, 0x00, 0x00, 0x00, 0x4D, 0x3C, 0x2B, 0x1A];
+        let mut p = BytesParser::from(pcapng);
+        assert_eq!(p.detect_endian(DEFAULT_ENDIAN_MAGICS), Some(ParsingEndian::LE));
+
+        // The pcapng byte-order magic alone is not enough, without the Section Header Block type
+        let not_pcapng: &[u8] = &[0x00, 0x00, 0x00, 0x00, 0x1C, 0x00, 0x00, 0x00, 0x4D, 0x3C, 0x2B, 0x1A];
+        let mut p = BytesParser::from(not_pcapng);
+        assert_eq!(p.detect_endian(DEFAULT_ENDIAN_MAGICS), None);
+
+        // Unknown patterns leave the endian unchanged
+        let unknown: &[u8] = &[0x00, 0x01, 0x02, 0x03];
+        let mut p = BytesParser::from(unknown);
+        assert_eq!(p.detect_endian(DEFAULT_ENDIAN_MAGICS), None);
+        assert_eq!(p.endian(), ParsingEndian::BE);
+
+        // Custom patterns can extend the default ones
+        let magics = [DEFAULT_ENDIAN_MAGICS, &[EndianMagic::new(1, &[0x01, 0x02], ParsingEndian::PDP)]].concat();
+        assert_eq!(p.detect_endian(&magics), Some(ParsingEndian::PDP));
+
+        // Patterns beyond any possible input never match
+        assert!(!EndianMagic::new(usize::MAX, &[0x01], ParsingEndian::LE).matches(unknown));
+    }
+
     #[test]
     fn try_parse_empty() {
         let input: &[u8] = &[];
@@ -594,6 +1185,13 @@ mod tests {
         assert_eq!(p.move_forward(4).unwrap_err(), BytesParserError::CursorOutOfBoundError(5, 3, 1));
 
         assert_eq!(p.move_backward(2).unwrap_err(), BytesParserError::CursorOutOfBoundError(-1, 3, 1));
+
+        assert_eq!(p.move_from_end(4).unwrap_err(), BytesParserError::CursorOutOfBoundError(-1, 3, 1));
+        assert_eq!(
+            p.move_from_end(1 << 63).unwrap_err(),
+            BytesParserError::CursorOutOfBoundError(3 - isize::MAX, 3, 1)
+        );
+        assert_eq!(p.from_end(4).unwrap_err(), BytesParserError::NotEnoughBytesForSlice(4));
     }
 
     #[test]
@@ -605,6 +1203,55 @@ mod tests {
         assert_eq!(p.parse_char_u32().unwrap_err(), BytesParserError::InvalidU32ForCharError);
     }
 
+    #[test]
+    fn parse_bool() {
+        let input: &[u8] = &[0x00, 0x01, 0x02];
+
+        let mut p = BytesParser::from(input);
+
+        assert!(!p.parse_bool().unwrap());
+        assert!(p.parse_bool().unwrap());
+        assert_eq!(p.parse_bool().unwrap_err(), BytesParserError::InvalidBoolError(2, 2));
+        assert_eq!(p.position(), 2);
+        assert!(p.parse_bool_lenient().unwrap());
+        assert_eq!(p.parse_bool_lenient().unwrap_err(), BytesParserError::NotEnoughBytesForTypeError("u8".to_string()));
+    }
+
+    #[test]
+    fn parse_enum() {
+        #[derive(Debug, PartialEq)]
+        enum MessageType {
+            Request,
+            Response,
+        }
+
+        impl TryFrom<u16> for MessageType {
+            type Error = ();
+
+            fn try_from(value: u16) -> Result<Self, Self::Error> {
+                match value {
+                    1 => Ok(MessageType::Request),
+                    2 => Ok(MessageType::Response),
+                    _ => Err(()),
+                }
+            }
+        }
+
+        let input: &[u8] = &[0x00, 0x01, 0x02, 0x00, 0x00, 0x03];
+
+        let mut p = BytesParser::from(input);
+
+        assert_eq!(p.parse_enum::<MessageType, u16>().unwrap(), MessageType::Request);
+        p.set_endian(ParsingEndian::LE);
+        assert_eq!(p.parse_enum::<MessageType, u16>().unwrap(), MessageType::Response);
+        p.set_endian(ParsingEndian::BE);
+        assert_eq!(
+            p.parse_enum::<MessageType, u16>().unwrap_err(),
+            BytesParserError::UnknownDiscriminantError("3".to_string(), 4)
+        );
+        assert_eq!(p.position(), 4);
+    }
+
     #[test]
     fn try_parsing_invalid_str() {
         let input: &[u8] = &[0, 159, 146, 150];
@@ -612,7 +1259,63 @@ mod tests {
         let mut p = BytesParser::from(input);
 
         let err = p.parse_str_utf8(4).unwrap_err();
-        assert_eq!(err.to_string(), "Failed to parse UTF-8 string: invalid utf-8 sequence of 1 bytes from index 1");
+        assert_eq!(
+            err.to_string(),
+            "Failed to parse UTF-8 string at position 1: invalid utf-8 sequence of 1 bytes from index 1"
+        );
         assert_eq!(err.source().unwrap().to_string(), "invalid utf-8 sequence of 1 bytes from index 1");
+        assert!(p.is_at_start());
+    }
+
+    #[test]
+    fn parse_invalid_str_and_continue() {
+        let input: &[u8] = &[
+            0x00, 0x04, 0x61, 0xFF, 0x62, 0x63, //< u16 + damaged string
+            0x00, 0x02, 0x68, 0x69, //< u16 + string
+        ];
+
+        let mut p = BytesParser::from(input);
+
+        let len = p.parse_u16().unwrap() as usize;
+        match p.parse_str_utf8_or_skip(len).unwrap_err() {
+            BytesParserError::StringParseError(err, position) => {
+                assert_eq!(err.valid_up_to(), 1);
+                assert_eq!(position, 3);
+            },
+            err => panic!("Unexpected error: {err}"),
+        }
+        assert_eq!(p.position(), 6);
+
+        let len = p.parse_u16().unwrap() as usize;
+        assert_eq!(p.parse_str_utf8_or_skip(len).unwrap(), "hi");
+        assert!(p.is_at_end());
+
+        p.reset();
+        assert!(p.move_forward(2).is_ok());
+        assert_eq!(p.parse_str_utf8_lossy(4).unwrap(), "a\u{FFFD}bc");
+        assert!(matches!(p.parse_str_utf8_lossy(4).unwrap(), Cow::Borrowed("\0\u{2}hi")));
+        assert_eq!(p.parse_str_utf8_lossy(1).unwrap_err(), BytesParserError::NotEnoughBytesForStringError(1));
+        assert_eq!(p.parse_str_utf8_or_skip(1).unwrap_err(), BytesParserError::NotEnoughBytesForStringError(1));
+    }
+
+    #[test]
+    fn parse_invalid_str_in_sub_parser() {
+        let input: &[u8] = &[
+            0x00, 0x00, //< header
+            0x61, 0x62, 0xFF, 0x0A, //< damaged line
+        ];
+
+        let mut p = BytesParser::from(input);
+        assert!(p.move_forward(2).is_ok());
+        let mut ps = p.from_slice(4).unwrap();
+
+        let expect_position = |err: BytesParserError| match err {
+            BytesParserError::StringParseError(_, position) => assert_eq!(position, 4),
+            err => panic!("Unexpected error: {err}"),
+        };
+        expect_position(ps.parse_str_utf8(4).unwrap_err());
+        expect_position(ps.parse_line_utf8().unwrap_err());
+        expect_position(ps.parse_str_fixed(4, StrPadding::Spaces).unwrap_err());
+        assert!(ps.is_at_start());
     }
 }
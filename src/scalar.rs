@@ -0,0 +1,97 @@
+use crate::endianness::ParsingEndian;
+
+use std::convert::TryInto;
+use std::mem;
+
+pub(crate) mod private {
+    use crate::endianness::ParsingEndian;
+
+    /// Crate-internal side of [`super::Scalar`]: it both seals it, and hides the decoding logic
+    /// that relies on being given slices of the right length.
+    pub trait Sealed: Sized {
+        /// Name of the scalar type, used when reporting errors.
+        const NAME: &'static str;
+
+        /// Reconstruct the scalar value from its byte-representation, using the given [`ParsingEndian`].
+        ///
+        /// The given `bytes` must be exactly [`super::Scalar::SIZE`] long.
+        fn from_endian_bytes(bytes: &[u8], endian: ParsingEndian) -> Self;
+
+        /// Reconstruct contiguous scalar values from their byte-representation, using the given [`ParsingEndian`].
+        ///
+        /// The given `bytes` must be exactly `values.len() * Scalar::SIZE` long.
+        fn from_endian_bytes_into(bytes: &[u8], endian: ParsingEndian, values: &mut [Self]);
+    }
+}
+
+/// A primitive scalar type that can be parsed from its byte-representation.
+///
+/// This is implemented for all the primitive numeric types, and it's what allows
+/// [`crate::BytesParser`] to share the parsing logic across all of them.
+///
+/// This trait is sealed: it cannot be implemented outside of this crate.
+pub trait Scalar: private::Sealed + Copy {
+    /// Amount of bytes occupied by the scalar type.
+    const SIZE: usize;
+}
+
+macro_rules! impl_scalar {
+    ($($scalar_type:ty),+) => {
+        $(
+            impl Scalar for $scalar_type {
+                const SIZE: usize = mem::size_of::<$scalar_type>();
+            }
+
+            impl private::Sealed for $scalar_type {
+                const NAME: &'static str = stringify!($scalar_type);
+
+                fn from_endian_bytes(bytes: &[u8], endian: ParsingEndian) -> Self {
+                    let mut bytes: [u8; mem::size_of::<$scalar_type>()] = bytes.try_into().unwrap();
+
+                    match endian {
+                        ParsingEndian::BE => <$scalar_type>::from_be_bytes(bytes),
+                        ParsingEndian::LE => <$scalar_type>::from_le_bytes(bytes),
+                        ParsingEndian::Native => <$scalar_type>::from_ne_bytes(bytes),
+                        ParsingEndian::PDP => {
+                            // Swapping the bytes of each 16-bit word turns it into big-endian
+                            for word in bytes.chunks_exact_mut(2) {
+                                word.swap(0, 1);
+                            }
+                            <$scalar_type>::from_be_bytes(bytes)
+                        },
+                        ParsingEndian::FPA if bytes.len() < 4 => <$scalar_type>::from_le_bytes(bytes),
+                        ParsingEndian::FPA => {
+                            // Reversing the bytes of each 32-bit word turns it into big-endian
+                            for word in bytes.chunks_exact_mut(4) {
+                                word.reverse();
+                            }
+                            <$scalar_type>::from_be_bytes(bytes)
+                        },
+                    }
+                }
+
+                fn from_endian_bytes_into(bytes: &[u8], endian: ParsingEndian, values: &mut [Self]) {
+                    let chunks = bytes.chunks_exact(Self::SIZE).zip(values.iter_mut());
+
+                    // Matching on the endian outside of the loops, lets the compiler vectorize them
+                    match endian {
+                        ParsingEndian::BE => {
+                            chunks.for_each(|(c, v)| *v = <$scalar_type>::from_be_bytes(c.try_into().unwrap()))
+                        },
+                        ParsingEndian::LE => {
+                            chunks.for_each(|(c, v)| *v = <$scalar_type>::from_le_bytes(c.try_into().unwrap()))
+                        },
+                        ParsingEndian::Native => {
+                            chunks.for_each(|(c, v)| *v = <$scalar_type>::from_ne_bytes(c.try_into().unwrap()))
+                        },
+                        ParsingEndian::PDP | ParsingEndian::FPA => {
+                            chunks.for_each(|(c, v)| *v = Self::from_endian_bytes(c, endian))
+                        },
+                    }
+                }
+            }
+        )+
+    };
+}
+
+impl_scalar!(i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, f32, f64, isize, usize);
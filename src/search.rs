@@ -0,0 +1,104 @@
+use crate::errors::BytesParserError;
+use crate::parser::BytesParser;
+
+use memchr::{memchr, memmem};
+
+impl<'a> BytesParser<'a> {
+    /// Search for the first occurrence of `pattern`, starting from [`Self::position`].
+    ///
+    /// Returns the 0-based position where `pattern` starts (i.e. comparable with [`Self::position`]),
+    /// or [`None`] if it's not found. The cursor is not moved.
+    ///
+    /// # Arguments
+    ///
+    /// * `pattern` - The bytes to search for.
+    pub fn find(&self, pattern: &[u8]) -> Option<usize> {
+        memmem::find(self.remaining(), pattern).map(|i| self.position() + i)
+    }
+
+    /// "Parse" a slice of bytes `&[u8]`, from [`Self::position`] up to the first occurrence of `pattern`.
+    ///
+    /// The returned slice does not include `pattern`, and the cursor is moved where `pattern` starts:
+    /// use [`Self::move_forward`] or [`Self::parse_slice`] to consume it, if needed.
+    /// It produces an error if `pattern` is not found: in that case, the cursor is not moved.
+    ///
+    /// The data returned is a "view" of the original bytes array, so that should be considered
+    /// when handling the returned reference and its lifetime.
+    ///
+    /// # Arguments
+    ///
+    /// * `pattern` - The bytes to search for (e.g. a multipart boundary).
+    pub fn take_until(&mut self, pattern: &[u8]) -> Result<&'a [u8], BytesParserError> {
+        match self.find(pattern) {
+            Some(position) => self.parse_slice(position - self.position()),
+            None => Err(BytesParserError::PatternNotFoundError(pattern.to_vec())),
+        }
+    }
+
+    /// "Parse" a slice of bytes `&[u8]`, from [`Self::position`] up to the first occurrence of `byte`.
+    ///
+    /// Like [`Self::take_until`], but searching for a single byte.
+    ///
+    /// # Arguments
+    ///
+    /// * `byte` - The byte to search for (e.g. a delimiter).
+    pub fn take_until_byte(&mut self, byte: u8) -> Result<&'a [u8], BytesParserError> {
+        match memchr(byte, self.remaining()) {
+            Some(size) => self.parse_slice(size),
+            None => Err(BytesParserError::PatternNotFoundError(vec![byte])),
+        }
+    }
+
+    /// Move internal cursor forward, to the first occurrence of `pattern`.
+    ///
+    /// Like [`Self::take_until`], but discarding the skipped bytes.
+    ///
+    /// # Arguments
+    ///
+    /// * `pattern` - The bytes to search for (e.g. a sync word).
+    pub fn skip_until(&mut self, pattern: &[u8]) -> Result<(), BytesParserError> {
+        self.take_until(pattern)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{BytesParser, BytesParserError};
+
+    #[test]
+    fn find_and_take_until() {
+        let input: &[u8] = b"preamble\r\n--boundary\r\nbody\r\n--boundary--";
+
+        let mut p = BytesParser::from(input);
+
+        assert_eq!(p.find(b"--boundary"), Some(10));
+        assert_eq!(p.find(b""), Some(0));
+        assert_eq!(p.take_until(b"\r\n--boundary").unwrap(), b"preamble");
+        assert_eq!(p.position(), 8);
+
+        assert!(p.move_forward(2).is_ok());
+        assert_eq!(p.find(b"--boundary"), Some(10));
+        assert!(p.move_forward(1).is_ok());
+        assert_eq!(p.find(b"--boundary"), Some(28));
+
+        assert!(p.skip_until(b"body").is_ok());
+        assert_eq!(p.take_until_byte(b'\r').unwrap(), b"body");
+        assert_eq!(p.take_until_byte(b'\r').unwrap(), b"");
+    }
+
+    #[test]
+    fn try_taking_until_missing_pattern() {
+        let input: &[u8] = &[0x47, 0x00, 0x11, 0x47];
+
+        let mut p = BytesParser::from(input);
+
+        assert!(p.move_forward(1).is_ok());
+        assert_eq!(p.find(&[0x47, 0x00]), None);
+        assert_eq!(p.take_until(&[0x47, 0x00]).unwrap_err(), BytesParserError::PatternNotFoundError(vec![0x47, 0x00]));
+        assert_eq!(p.take_until_byte(0xFF).unwrap_err(), BytesParserError::PatternNotFoundError(vec![0xFF]));
+        assert_eq!(p.skip_until(&[0x11, 0x48]).unwrap_err(), BytesParserError::PatternNotFoundError(vec![0x11, 0x48]));
+        assert_eq!(p.position(), 1);
+    }
+}
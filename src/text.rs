@@ -0,0 +1,478 @@
+use crate::codepage::{CP1252_0X80_0X9F, EBCDIC_CP037, EBCDIC_CP1140, EBCDIC_CP273, EBCDIC_CP500};
+use crate::errors::BytesParserError;
+use crate::parser::BytesParser;
+
+use memchr::memchr;
+use std::borrow::Cow;
+use std::str;
+
+/// Control which padding is stripped from the end of fixed-width string fields.
+///
+/// See [`BytesParser::parse_str_fixed`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum StrPadding {
+    /// Strip trailing NULs (`0x00`) only: any NUL in the middle of the field is kept.
+    ///
+    /// For C strings in fixed-size buffers, see [`StrPadding::NulTerminated`].
+    Nuls,
+
+    /// Cut the field at the first NUL (`0x00`), like C strings in fixed-size buffers (e.g. TAR names):
+    /// anything after it is ignored, even if it's not NULs.
+    NulTerminated,
+
+    /// Strip trailing spaces (`0x20`), like space-padded fields (e.g. ISO 9660, SCSI inquiry data).
+    Spaces,
+
+    /// Strip any trailing NULs and spaces.
+    NulsOrSpaces,
+}
+
+impl StrPadding {
+    /// Returns the given `bytes`, without the trailing padding.
+    fn strip<'b>(&self, bytes: &'b [u8]) -> &'b [u8] {
+        if *self == StrPadding::NulTerminated {
+            let end = memchr(0, bytes).unwrap_or(bytes.len());
+            return &bytes[..end];
+        }
+
+        let is_padding = |b: &u8| match self {
+            StrPadding::Nuls | StrPadding::NulTerminated => *b == 0,
+            StrPadding::Spaces => *b == b' ',
+            StrPadding::NulsOrSpaces => *b == 0 || *b == b' ',
+        };
+        let end = bytes.iter().rposition(|b| !is_padding(b)).map_or(0, |i| i + 1);
+
+        &bytes[..end]
+    }
+}
+
+/// Supported [EBCDIC](https://en.wikipedia.org/wiki/EBCDIC) code pages.
+///
+/// See [`BytesParser::parse_str_ebcdic`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum EbcdicCodePage {
+    /// Code page 037: USA, Canada and others.
+    Cp037,
+
+    /// Code page 273: Germany, Austria.
+    Cp273,
+
+    /// Code page 500: International.
+    Cp500,
+
+    /// Code page 1140: like [`EbcdicCodePage::Cp037`], but with the Euro sign (`€`) at `0x9F`.
+    Cp1140,
+}
+
+impl EbcdicCodePage {
+    /// Returns the decoding table of the code page.
+    fn table(&self) -> &'static [char; 256] {
+        match self {
+            EbcdicCodePage::Cp037 => &EBCDIC_CP037,
+            EbcdicCodePage::Cp273 => &EBCDIC_CP273,
+            EbcdicCodePage::Cp500 => &EBCDIC_CP500,
+            EbcdicCodePage::Cp1140 => &EBCDIC_CP1140,
+        }
+    }
+}
+
+/// Decode the given `bytes`, mapping each byte to a [`char`] with `decode_byte`.
+///
+/// If all the bytes are ASCII and `ascii_compatible` is [`true`], the bytes are borrowed instead.
+fn decode_single_byte<F>(bytes: &[u8], ascii_compatible: bool, decode_byte: F) -> Cow<'_, str>
+where
+    F: Fn(u8) -> char,
+{
+    if ascii_compatible && bytes.is_ascii() {
+        return Cow::Borrowed(str::from_utf8(bytes).unwrap());
+    }
+
+    Cow::Owned(bytes.iter().map(|b| decode_byte(*b)).collect())
+}
+
+/// Decode the given `bytes` as [CESU-8](https://en.wikipedia.org/wiki/CESU-8), or as Java's
+/// [Modified UTF-8](https://en.wikipedia.org/wiki/UTF-8#Modified_UTF-8) if `mutf8` is [`true`].
+///
+/// If the bytes are also valid UTF-8 (i.e. no NULs encoded as `0xC0 0x80`, and no surrogate pairs),
+/// they are borrowed instead. In case of error, it returns the index of the invalid byte sequence.
+fn decode_cesu8(bytes: &[u8], mutf8: bool) -> Result<Cow<'_, str>, usize> {
+    if bytes.iter().all(|b| *b < 0xF0) {
+        if let Ok(result) = str::from_utf8(bytes) {
+            return Ok(Cow::Borrowed(result));
+        }
+    }
+
+    let continuation = |i: usize| bytes.get(i).filter(|b| *b & 0xC0 == 0x80).map(|b| (*b & 0x3F) as u32);
+    let three_bytes = |i: usize| -> Option<u32> {
+        let value = ((bytes[i] & 0x0F) as u32) << 12 | continuation(i + 1)? << 6 | continuation(i + 2)?;
+        (bytes[i] & 0xF0 == 0xE0 && value >= 0x800).then_some(value)
+    };
+
+    let mut result = String::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let (c, size) = match bytes[i] {
+            b @ 0x00..=0x7F => (Some(b as u32), 1),
+            0xC0..=0xDF => {
+                let value = ((bytes[i] & 0x1F) as u32) << 6 | continuation(i + 1).ok_or(i)?;
+                // The only overlong encoding allowed, is NUL in Modified UTF-8
+                (Some(value).filter(|v| *v >= 0x80 || (mutf8 && bytes[i] == 0xC0 && *v == 0)), 2)
+            },
+            0xE0..=0xEF => match three_bytes(i).ok_or(i)? {
+                high @ 0xD800..=0xDBFF => {
+                    let low =
+                        bytes.get(i + 3).and_then(|_| three_bytes(i + 3)).filter(|l| (0xDC00..=0xDFFF).contains(l));
+                    (low.map(|low| 0x10000 + ((high - 0xD800) << 10) + (low - 0xDC00)), 6)
+                },
+                value => (Some(value), 3),
+            },
+            _ => (None, 1),
+        };
+
+        // Unpaired surrogates are rejected by `char::from_u32`
+        result.push(c.and_then(char::from_u32).ok_or(i)?);
+        i += size;
+    }
+
+    Ok(Cow::Owned(result))
+}
+
+impl<'a> BytesParser<'a> {
+    /// Parse a UTF-8 [`&str`] from a fixed-width field, stripping its trailing `padding`,
+    /// and update the internal cursor accordingly.
+    ///
+    /// The cursor always moves by `width` bytes, regardless of how much padding is stripped.
+    /// It produces an error if [`BytesParser::parseable`] returns an amount inferior to `width`,
+    /// or if the field, once stripped, is not valid UTF-8. In case of error, the cursor is not moved.
+    ///
+    /// The data returned is a "view" of the original bytes array, so that should be considered
+    /// when handling the returned reference and its lifetime.
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - Width of the field, in bytes.
+    /// * `padding` - The [StrPadding] to strip from the end of the field.
+    pub fn parse_str_fixed(&mut self, width: usize, padding: StrPadding) -> Result<&'a str, BytesParserError> {
+        let mut p = *self;
+        let field = p.parse_fixed_field(width, padding)?;
+        let result = str::from_utf8(field)
+            .map_err(|err| BytesParserError::StringParseError(err, self.absolute_position() + err.valid_up_to()))?;
+
+        *self = p;
+        Ok(result)
+    }
+
+    /// Parse a UTF-8 string from a fixed-width field, stripping its trailing `padding`,
+    /// and update the internal cursor accordingly.
+    ///
+    /// Like [`Self::parse_str_fixed`], but invalid UTF-8 sequences are replaced with
+    /// [`char::REPLACEMENT_CHARACTER`] instead of producing an error:
+    /// the string is borrowed from the original bytes array, unless a replacement was necessary.
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - Width of the field, in bytes.
+    /// * `padding` - The [StrPadding] to strip from the end of the field.
+    pub fn parse_str_fixed_lossy(
+        &mut self,
+        width: usize,
+        padding: StrPadding,
+    ) -> Result<Cow<'a, str>, BytesParserError> {
+        let field = self.parse_fixed_field(width, padding)?;
+
+        Ok(String::from_utf8_lossy(field))
+    }
+
+    /// Parse a strictly ASCII [`&str`], and update the internal cursor accordingly.
+    ///
+    /// It produces an error if [`BytesParser::parseable`] returns an amount inferior to the given
+    /// `size`, or if any of the bytes is not ASCII (i.e. greater than `0x7F`).
+    /// In case of error, the cursor is not moved.
+    ///
+    /// The data returned is a "view" of the original bytes array, so that should be considered
+    /// when handling the returned reference and its lifetime.
+    ///
+    /// # Arguments
+    ///
+    /// * `size` - Size of the ASCII [`&str`] to parse, in bytes.
+    pub fn parse_str_ascii(&mut self, size: usize) -> Result<&'a str, BytesParserError> {
+        let bytes = self.peek_str_bytes(size)?;
+        if let Some(i) = bytes.iter().position(|b| !b.is_ascii()) {
+            return Err(BytesParserError::StringDecodeError("ASCII".to_string(), self.absolute_position() + i));
+        }
+
+        self.parse_str_utf8(size)
+    }
+
+    /// Parse a [Latin-1](https://en.wikipedia.org/wiki/ISO/IEC_8859-1) (ISO 8859-1) string,
+    /// and update the internal cursor accordingly.
+    ///
+    /// Every byte is a valid Latin-1 character, so this only produces an error if
+    /// [`BytesParser::parseable`] returns an amount inferior to the given `size`.
+    /// The string is borrowed from the original bytes array if it's all ASCII.
+    ///
+    /// # Arguments
+    ///
+    /// * `size` - Size of the Latin-1 string to parse, in bytes.
+    pub fn parse_str_latin1(&mut self, size: usize) -> Result<Cow<'a, str>, BytesParserError> {
+        let bytes = self.peek_str_bytes(size)?;
+        self.parse_slice(size)?;
+
+        Ok(decode_single_byte(bytes, true, char::from))
+    }
+
+    /// Parse a [Windows-1252](https://en.wikipedia.org/wiki/Windows-1252) string,
+    /// and update the internal cursor accordingly.
+    ///
+    /// The 5 bytes left undefined by Windows-1252 (i.e. `0x81`, `0x8D`, `0x8F`, `0x90` and `0x9D`)
+    /// are decoded to the corresponding C1 control characters, like Windows itself does.
+    /// So this only produces an error if [`BytesParser::parseable`] returns an amount inferior to
+    /// the given `size`. The string is borrowed from the original bytes array if it's all ASCII.
+    ///
+    /// # Arguments
+    ///
+    /// * `size` - Size of the Windows-1252 string to parse, in bytes.
+    pub fn parse_str_cp1252(&mut self, size: usize) -> Result<Cow<'a, str>, BytesParserError> {
+        let bytes = self.peek_str_bytes(size)?;
+        self.parse_slice(size)?;
+
+        Ok(decode_single_byte(bytes, true, |b| match b {
+            0x80..=0x9F => CP1252_0X80_0X9F[(b - 0x80) as usize],
+            _ => char::from(b),
+        }))
+    }
+
+    /// Parse an [EBCDIC](https://en.wikipedia.org/wiki/EBCDIC) string, using the given code page,
+    /// and update the internal cursor accordingly.
+    ///
+    /// Every byte is a valid character in the supported code pages, so this only produces an error
+    /// if [`BytesParser::parseable`] returns an amount inferior to the given `size`.
+    ///
+    /// # Arguments
+    ///
+    /// * `size` - Size of the EBCDIC string to parse, in bytes.
+    /// * `code_page` - The [EbcdicCodePage] the string is encoded with.
+    pub fn parse_str_ebcdic(
+        &mut self,
+        size: usize,
+        code_page: EbcdicCodePage,
+    ) -> Result<Cow<'a, str>, BytesParserError> {
+        let bytes = self.peek_str_bytes(size)?;
+        self.parse_slice(size)?;
+
+        let table = code_page.table();
+        Ok(decode_single_byte(bytes, false, |b| table[b as usize]))
+    }
+
+    /// Parse a [Modified UTF-8](https://en.wikipedia.org/wiki/UTF-8#Modified_UTF-8) string,
+    /// and update the internal cursor accordingly.
+    ///
+    /// Modified UTF-8 is the encoding used by Java class files, JNI and Android DEX files:
+    /// it differs from UTF-8 because NUL is encoded as `0xC0 0x80`, and supplementary characters
+    /// are encoded as a pair of surrogates, 3 bytes each. Plain `0x00` bytes are tolerated.
+    /// The string is borrowed from the original bytes array if it's also valid UTF-8.
+    ///
+    /// It produces an error if [`BytesParser::parseable`] returns an amount inferior to the given
+    /// `size`, or if the bytes are not valid Modified UTF-8. In case of error, the cursor is not moved.
+    ///
+    /// # Arguments
+    ///
+    /// * `size` - Size of the Modified UTF-8 string to parse, in bytes.
+    pub fn parse_str_mutf8(&mut self, size: usize) -> Result<Cow<'a, str>, BytesParserError> {
+        let bytes = self.peek_str_bytes(size)?;
+        let result = decode_cesu8(bytes, true).map_err(|i| {
+            BytesParserError::StringDecodeError("Modified UTF-8".to_string(), self.absolute_position() + i)
+        })?;
+        self.parse_slice(size)?;
+
+        Ok(result)
+    }
+
+    /// Parse a [CESU-8](https://en.wikipedia.org/wiki/CESU-8) string, and update the internal
+    /// cursor accordingly.
+    ///
+    /// CESU-8 differs from UTF-8 because supplementary characters are encoded as a pair of
+    /// surrogates, 3 bytes each. Differently from [`Self::parse_str_mutf8`], NUL is encoded as `0x00`.
+    /// The string is borrowed from the original bytes array if it's also valid UTF-8.
+    ///
+    /// It produces an error if [`BytesParser::parseable`] returns an amount inferior to the given
+    /// `size`, or if the bytes are not valid CESU-8. In case of error, the cursor is not moved.
+    ///
+    /// # Arguments
+    ///
+    /// * `size` - Size of the CESU-8 string to parse, in bytes.
+    pub fn parse_str_cesu8(&mut self, size: usize) -> Result<Cow<'a, str>, BytesParserError> {
+        let bytes = self.peek_str_bytes(size)?;
+        let result = decode_cesu8(bytes, false)
+            .map_err(|i| BytesParserError::StringDecodeError("CESU-8".to_string(), self.absolute_position() + i))?;
+        self.parse_slice(size)?;
+
+        Ok(result)
+    }
+
+    /// Returns the bytes of a string of given `size`, without moving the cursor.
+    fn peek_str_bytes(&self, size: usize) -> Result<&'a [u8], BytesParserError> {
+        if self.parseable() < size {
+            return Err(BytesParserError::NotEnoughBytesForStringError(size));
+        }
+
+        Ok(&self.remaining()[..size])
+    }
+
+    /// Cut a fixed-width field, stripping its trailing `padding`, and update the internal cursor accordingly.
+    fn parse_fixed_field(&mut self, width: usize, padding: StrPadding) -> Result<&'a [u8], BytesParserError> {
+        if self.parseable() < width {
+            return Err(BytesParserError::NotEnoughBytesForStringError(width));
+        }
+
+        Ok(padding.strip(self.parse_slice(width)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{BytesParser, BytesParserError, EbcdicCodePage, StrPadding};
+    use std::borrow::Cow;
+
+    #[test]
+    fn parse_str_fixed() {
+        let input: &[u8] = b"ustar\0\0\0SEAGATE ST3500 \0 \0\0\0\0";
+
+        let mut p = BytesParser::from(input);
+
+        assert_eq!(p.parse_str_fixed(8, StrPadding::Nuls).unwrap(), "ustar");
+        assert_eq!(p.parse_str_fixed(8, StrPadding::Spaces).unwrap(), "SEAGATE");
+        assert_eq!(p.parse_str_fixed(8, StrPadding::NulsOrSpaces).unwrap(), "ST3500");
+        assert_eq!(p.parse_str_fixed(5, StrPadding::NulsOrSpaces).unwrap(), "");
+        assert!(p.is_at_end());
+
+        let input: &[u8] = b"abc\0xyz\0\0";
+        let mut p = BytesParser::from(input);
+
+        assert_eq!(p.parse_str_fixed(9, StrPadding::Nuls).unwrap(), "abc\0xyz");
+        p.reset();
+        assert_eq!(p.parse_str_fixed(9, StrPadding::NulTerminated).unwrap(), "abc");
+        assert!(p.is_at_end());
+        p.reset();
+        assert_eq!(p.parse_str_fixed(3, StrPadding::NulTerminated).unwrap(), "abc");
+    }
+
+    #[test]
+    fn parse_str_fixed_lossy() {
+        let input: &[u8] = b"caf\xE9\0\0name\0\0";
+
+        let mut p = BytesParser::from(input);
+
+        assert!(p.parse_str_fixed(6, StrPadding::Nuls).is_err());
+        assert!(p.is_at_start());
+        assert_eq!(p.parse_str_fixed_lossy(6, StrPadding::Nuls).unwrap(), "caf\u{FFFD}");
+        assert_eq!(p.parse_str_fixed_lossy(6, StrPadding::Nuls).unwrap(), "name");
+
+        p.reset();
+        assert_eq!(
+            p.parse_str_fixed_lossy(13, StrPadding::Nuls).unwrap_err(),
+            BytesParserError::NotEnoughBytesForStringError(13)
+        );
+    }
+
+    #[test]
+    fn parse_str_legacy_encodings() {
+        let input: &[u8] = &[
+            0x4E, 0x61, 0x70, 0x6F, 0x6C, 0x69, //< "Napoli", ASCII
+            0x63, 0x61, 0x66, 0xE9, //< "café", Latin-1
+            0x80, 0x20, 0x93, 0x51, 0x94, //< "€ “Q”", Windows-1252
+            0xC8, 0x85, 0x93, 0x93, 0x96, 0x40, 0x9F, //< "Hello €", EBCDIC 1140
+        ];
+
+        let mut p = BytesParser::from(input);
+
+        assert_eq!(p.parse_str_ascii(6).unwrap(), "Napoli");
+        assert_eq!(p.parse_str_latin1(4).unwrap(), "café");
+        assert_eq!(p.parse_str_cp1252(5).unwrap(), "€ “Q”");
+        assert_eq!(p.parse_str_ebcdic(7, EbcdicCodePage::Cp1140).unwrap(), "Hello €");
+        assert!(p.is_at_end());
+
+        p.reset();
+        assert!(matches!(p.parse_str_latin1(6).unwrap(), Cow::Borrowed("Napoli")));
+        assert!(matches!(p.parse_str_cp1252(4).unwrap(), Cow::Owned(_)));
+
+        p.reset();
+        assert_eq!(p.parse_str_ebcdic(4, EbcdicCodePage::Cp037).unwrap(), "+/ø?");
+    }
+
+    #[test]
+    fn parse_str_ebcdic_code_pages() {
+        let input: &[u8] = &[0xC1, 0xF1, 0x4A, 0x5A, 0x9F, 0xC0];
+
+        let mut p = BytesParser::from(input);
+
+        assert_eq!(p.parse_str_ebcdic(6, EbcdicCodePage::Cp037).unwrap(), "A1¢!¤{");
+        p.reset();
+        assert_eq!(p.parse_str_ebcdic(6, EbcdicCodePage::Cp500).unwrap(), "A1[]¤{");
+        p.reset();
+        assert_eq!(p.parse_str_ebcdic(6, EbcdicCodePage::Cp273).unwrap(), "A1ÄÜ¤ä");
+    }
+
+    #[test]
+    fn try_parsing_invalid_ascii_str() {
+        let input: &[u8] = &[0x41, 0x42, 0xC3, 0xA9];
+
+        let mut p = BytesParser::from(input);
+
+        assert_eq!(p.parse_str_ascii(4).unwrap_err(), BytesParserError::StringDecodeError("ASCII".to_string(), 2));
+        assert!(p.is_at_start());
+        assert_eq!(p.parse_str_ascii(5).unwrap_err(), BytesParserError::NotEnoughBytesForStringError(5));
+        assert_eq!(p.parse_str_ascii(2).unwrap(), "AB");
+
+        p.reset();
+        assert!(p.move_forward(1).is_ok());
+        let mut sub = p.from_slice(3).unwrap();
+        assert_eq!(sub.parse_str_ascii(3).unwrap_err(), BytesParserError::StringDecodeError("ASCII".to_string(), 2));
+        assert!(sub.is_at_start());
+    }
+
+    #[test]
+    fn parse_str_mutf8_and_cesu8() {
+        let input: &[u8] = &[
+            0x4A, 0x61, 0x76, 0x61, //< "Java", plain ASCII
+            0x61, 0xC0, 0x80, 0x62, //< "a\0b", NUL in Modified UTF-8
+            0xED, 0xA0, 0xBE, 0xED, 0xB6, 0x80, //< "🦀", surrogate pair
+            0xC3, 0xA8, 0x00, //< "è\0", CESU-8
+        ];
+
+        let mut p = BytesParser::from(input);
+
+        assert!(matches!(p.parse_str_mutf8(4).unwrap(), Cow::Borrowed("Java")));
+        assert_eq!(p.parse_str_mutf8(4).unwrap(), "a\0b");
+        assert_eq!(p.parse_str_mutf8(6).unwrap(), "🦀");
+        assert_eq!(p.parse_str_cesu8(3).unwrap(), "è\0");
+        assert!(p.is_at_end());
+
+        assert!(p.move_at(8).is_ok());
+        assert_eq!(p.parse_str_cesu8(6).unwrap(), "🦀");
+    }
+
+    #[test]
+    fn try_parsing_invalid_mutf8_and_cesu8() {
+        let input: &[u8] = &[
+            0x61, 0xC0, 0x80, //< NUL as 0xC0 0x80, invalid in CESU-8
+            0xED, 0xA0, 0xBE, 0x61, //< unpaired high surrogate
+            0xF0, 0x9F, 0xA6, 0x80, //< 4 bytes UTF-8, invalid in both
+        ];
+
+        let mut p = BytesParser::from(input);
+
+        assert_eq!(p.parse_str_cesu8(3).unwrap_err(), BytesParserError::StringDecodeError("CESU-8".to_string(), 1));
+        assert!(p.is_at_start());
+        assert_eq!(
+            p.parse_str_mutf8(7).unwrap_err(),
+            BytesParserError::StringDecodeError("Modified UTF-8".to_string(), 3)
+        );
+        assert!(p.move_at(7).is_ok());
+        assert_eq!(
+            p.parse_str_mutf8(4).unwrap_err(),
+            BytesParserError::StringDecodeError("Modified UTF-8".to_string(), 7)
+        );
+        assert_eq!(p.parse_str_cesu8(2).unwrap_err(), BytesParserError::StringDecodeError("CESU-8".to_string(), 7));
+    }
+}
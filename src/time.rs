@@ -0,0 +1,298 @@
+use crate::errors::BytesParserError;
+use crate::parser::BytesParser;
+
+use std::fmt;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Seconds between the NTP epoch (1900-01-01) and the Unix epoch (1970-01-01).
+const NTP_TO_UNIX_SECS: i64 = 2_208_988_800;
+
+/// Seconds in an NTP era (i.e. 2^32), the first of which (era 0) started on 1900-01-01.
+const NTP_ERA_SECS: i64 = 1 << 32;
+
+/// Seconds between the Windows `FILETIME` epoch (1601-01-01) and the Unix epoch (1970-01-01).
+const FILETIME_TO_UNIX_SECS: i64 = 11_644_473_600;
+
+/// Amount of 100 nanoseconds intervals (i.e. Windows `FILETIME` ticks) in a second.
+const FILETIME_TICKS_PER_SEC: u64 = 10_000_000;
+
+/// Seconds between the Mac HFS epoch (1904-01-01) and the Unix epoch (1970-01-01).
+const HFS_TO_UNIX_SECS: i64 = 2_082_844_800;
+
+/// Build a [`SystemTime`] from seconds (possibly negative) and nanoseconds since the Unix epoch.
+///
+/// Returns [`None`] if the result can't be represented on the current platform.
+fn from_unix_time(secs: i64, nanos: u32) -> Option<SystemTime> {
+    let time = if secs >= 0 {
+        UNIX_EPOCH.checked_add(Duration::from_secs(secs as u64))?
+    } else {
+        UNIX_EPOCH.checked_sub(Duration::from_secs(secs.unsigned_abs()))?
+    };
+
+    time.checked_add(Duration::from_nanos(nanos as u64))
+}
+
+/// A date and time in the [MS-DOS](https://learn.microsoft.com/en-us/windows/win32/api/winbase/nf-winbase-dosdatetimetofiletime)
+/// packed format, as used by FAT file systems and ZIP archives.
+///
+/// It has no time zone (i.e. it's local time) and a resolution of 2 seconds.
+///
+/// See [`BytesParser::parse_dos_datetime`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct DosDateTime {
+    /// Year, from `1980` to `2107`.
+    pub year: u16,
+    /// Month, from `1` to `12`.
+    pub month: u8,
+    /// Day of the month, from `1` to `31`.
+    pub day: u8,
+    /// Hour, from `0` to `23`.
+    pub hour: u8,
+    /// Minute, from `0` to `59`.
+    pub minute: u8,
+    /// Second, from `0` to `58` (always even).
+    pub second: u8,
+}
+
+impl DosDateTime {
+    /// Returns [`true`] if all the fields are within range, including the day for the given month and year.
+    fn is_valid(&self) -> bool {
+        let leap = self.year.is_multiple_of(4) && (!self.year.is_multiple_of(100) || self.year.is_multiple_of(400));
+        let days_in_month = match self.month {
+            1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+            4 | 6 | 9 | 11 => 30,
+            2 if leap => 29,
+            2 => 28,
+            _ => return false,
+        };
+
+        (1..=days_in_month).contains(&self.day) && self.hour < 24 && self.minute < 60 && self.second < 60
+    }
+}
+
+impl fmt::Display for DosDateTime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:04}-{:02}-{:02} {:02}:{:02}:{:02}",
+            self.year, self.month, self.day, self.hour, self.minute, self.second
+        )
+    }
+}
+
+impl<'a> BytesParser<'a> {
+    /// Parse a [Unix time](https://en.wikipedia.org/wiki/Unix_time) from a [`u32`] of seconds
+    /// since 1970-01-01 UTC, and update the internal cursor accordingly.
+    ///
+    /// The seconds are parsed using the [`crate::ParsingEndian`] currently in use.
+    pub fn parse_unix_time_32(&mut self) -> Result<SystemTime, BytesParserError> {
+        let mut p = *self;
+        let secs = p.parse_u32()?;
+        let time = from_unix_time(secs as i64, 0)
+            .ok_or_else(|| BytesParserError::TimestampOutOfRangeError(secs.to_string(), self.position()))?;
+
+        *self = p;
+        Ok(time)
+    }
+
+    /// Parse a [Unix time](https://en.wikipedia.org/wiki/Unix_time) from an [`i64`] of seconds
+    /// since 1970-01-01 UTC, and update the internal cursor accordingly.
+    ///
+    /// The seconds are parsed using the [`crate::ParsingEndian`] currently in use, and can be negative.
+    /// It produces an error if the time can't be represented by [`SystemTime`] on the current platform:
+    /// in that case, the cursor is not moved.
+    pub fn parse_unix_time_64(&mut self) -> Result<SystemTime, BytesParserError> {
+        let mut p = *self;
+        let secs = p.parse_i64()?;
+        let time = from_unix_time(secs, 0)
+            .ok_or_else(|| BytesParserError::TimestampOutOfRangeError(secs.to_string(), self.position()))?;
+
+        *self = p;
+        Ok(time)
+    }
+
+    /// Parse an [NTP timestamp](https://en.wikipedia.org/wiki/Network_Time_Protocol#Timestamps)
+    /// (i.e. 8 bytes), and update the internal cursor accordingly.
+    ///
+    /// It's a 32.32 fixed-point number of seconds since 1900-01-01 UTC: a [`u32`] of seconds,
+    /// followed by a [`u32`] of fractions of second, both parsed using the [`crate::ParsingEndian`]
+    /// currently in use. In case of error, the cursor is not moved.
+    ///
+    /// As seconds wrap around every 2^32 (i.e. in 2036), the era is inferred as described by
+    /// [RFC 4330 §3](https://www.rfc-editor.org/rfc/rfc4330#section-3): if the most significant bit
+    /// of the seconds is set, the timestamp is in era 0 (1968-2036), otherwise in era 1 (2036-2104).
+    pub fn parse_ntp_timestamp(&mut self) -> Result<SystemTime, BytesParserError> {
+        let mut p = *self;
+        let secs = p.parse_u32()?;
+        let fraction = p.parse_u32()?;
+
+        let era_secs = if secs & 0x8000_0000 != 0 {
+            0
+        } else {
+            NTP_ERA_SECS
+        };
+        let nanos = ((fraction as u64 * 1_000_000_000) >> 32) as u32;
+        let time = from_unix_time(era_secs + secs as i64 - NTP_TO_UNIX_SECS, nanos)
+            .ok_or_else(|| BytesParserError::TimestampOutOfRangeError(secs.to_string(), self.position()))?;
+
+        *self = p;
+        Ok(time)
+    }
+
+    /// Parse a Windows [`FILETIME`](https://learn.microsoft.com/en-us/windows/win32/api/minwinbase/ns-minwinbase-filetime)
+    /// from a [`u64`] of 100 nanoseconds intervals since 1601-01-01 UTC, and update the internal cursor accordingly.
+    ///
+    /// The intervals are parsed using the [`crate::ParsingEndian`] currently in use.
+    /// It produces an error if the time can't be represented by [`SystemTime`] on the current platform:
+    /// in that case, the cursor is not moved.
+    pub fn parse_windows_filetime(&mut self) -> Result<SystemTime, BytesParserError> {
+        let mut p = *self;
+        let ticks = p.parse_u64()?;
+
+        let secs = (ticks / FILETIME_TICKS_PER_SEC) as i64 - FILETIME_TO_UNIX_SECS;
+        let nanos = (ticks % FILETIME_TICKS_PER_SEC) as u32 * 100;
+        let time = from_unix_time(secs, nanos)
+            .ok_or_else(|| BytesParserError::TimestampOutOfRangeError(ticks.to_string(), self.position()))?;
+
+        *self = p;
+        Ok(time)
+    }
+
+    /// Parse a Mac [HFS](https://en.wikipedia.org/wiki/Hierarchical_File_System) time from a [`u32`]
+    /// of seconds since 1904-01-01, and update the internal cursor accordingly.
+    ///
+    /// The seconds are parsed using the [`crate::ParsingEndian`] currently in use.
+    /// HFS+ stores times in UTC, while the original HFS stores them in local time:
+    /// in the latter case, the returned time has to be adjusted by the caller.
+    pub fn parse_mac_hfs_time(&mut self) -> Result<SystemTime, BytesParserError> {
+        let mut p = *self;
+        let secs = p.parse_u32()?;
+        let time = from_unix_time(secs as i64 - HFS_TO_UNIX_SECS, 0)
+            .ok_or_else(|| BytesParserError::TimestampOutOfRangeError(secs.to_string(), self.position()))?;
+
+        *self = p;
+        Ok(time)
+    }
+
+    /// Parse a [`DosDateTime`] (i.e. 4 bytes), and update the internal cursor accordingly.
+    ///
+    /// It's made of a [`u16`] of packed time, followed by a [`u16`] of packed date (i.e. the order used
+    /// by ZIP archives), both parsed using the [`crate::ParsingEndian`] currently in use.
+    /// It produces an error if any field is out of range (e.g. month `13`, or February 30th):
+    /// in that case, the cursor is not moved.
+    pub fn parse_dos_datetime(&mut self) -> Result<DosDateTime, BytesParserError> {
+        let mut p = *self;
+        let time = p.parse_u16()?;
+        let date = p.parse_u16()?;
+
+        let datetime = DosDateTime {
+            year: 1980 + (date >> 9),
+            month: ((date >> 5) & 0x0F) as u8,
+            day: (date & 0x1F) as u8,
+            hour: (time >> 11) as u8,
+            minute: ((time >> 5) & 0x3F) as u8,
+            second: ((time & 0x1F) * 2) as u8,
+        };
+        if !datetime.is_valid() {
+            return Err(BytesParserError::TimestampOutOfRangeError(datetime.to_string(), self.position()));
+        }
+
+        *self = p;
+        Ok(datetime)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{BytesParser, BytesParserError, DosDateTime, ParsingEndian};
+    use std::time::{Duration, UNIX_EPOCH};
+
+    #[test]
+    fn parse_timestamps() {
+        let input: &[u8] = &[
+            0x65, 0x92, 0x00, 0x80, //< Unix time 32: 2024-01-01T00:00:00Z
+            0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, //< Unix time 64: -1
+            0xE9, 0x3C, 0x7F, 0x00, 0x80, 0x00, 0x00, 0x00, //< NTP: 2024-01-01T00:00:00.5Z
+            0x01, 0xDA, 0x3C, 0x45, 0x76, 0x89, 0xC0, 0x0A, //< FILETIME: 2024-01-01T00:00:00.000001Z
+            0xE1, 0xB7, 0xB1, 0x00, //< HFS: 2024-01-01T00:00:00Z
+        ];
+        let new_year_2024 = UNIX_EPOCH + Duration::from_secs(1_704_067_200);
+
+        let mut p = BytesParser::from(input);
+
+        assert_eq!(p.parse_unix_time_32().unwrap(), new_year_2024);
+        assert_eq!(p.parse_unix_time_64().unwrap(), UNIX_EPOCH - Duration::from_secs(1));
+        assert_eq!(p.parse_ntp_timestamp().unwrap(), new_year_2024 + Duration::from_millis(500));
+        assert_eq!(p.parse_windows_filetime().unwrap(), new_year_2024 + Duration::from_micros(1));
+        assert_eq!(p.parse_mac_hfs_time().unwrap(), new_year_2024);
+        assert!(p.is_at_end());
+    }
+
+    #[test]
+    fn parse_ntp_timestamp_after_era_rollover() {
+        let input: &[u8] = &[
+            0xFF, 0xFF, 0xFF, 0xFF, 0x00, 0x00, 0x00, 0x00, //< NTP: 2036-02-07T06:28:15Z, end of era 0
+            0x00, 0x00, 0x00, 0x10, 0x40, 0x00, 0x00, 0x00, //< NTP: 2036-02-07T06:28:32.25Z, era 1
+        ];
+        let end_of_era_0 = UNIX_EPOCH + Duration::from_secs(2_085_978_495);
+
+        let mut p = BytesParser::from(input);
+
+        assert_eq!(p.parse_ntp_timestamp().unwrap(), end_of_era_0);
+        assert_eq!(p.parse_ntp_timestamp().unwrap(), end_of_era_0 + Duration::from_millis(17_250));
+        assert!(p.is_at_end());
+    }
+
+    #[test]
+    fn parse_dos_datetime() {
+        let input: &[u8] = &[
+            0x7D, 0x9C, 0x5D, 0x58, //< 2024-02-29 19:35:58
+            0x00, 0x00, 0x21, 0x00, //< 1980-01-01 00:00:00
+        ];
+
+        let mut p = BytesParser::from(input);
+        p.set_endian(ParsingEndian::LE);
+
+        let datetime = p.parse_dos_datetime().unwrap();
+        assert_eq!(
+            datetime,
+            DosDateTime {
+                year: 2024,
+                month: 2,
+                day: 29,
+                hour: 19,
+                minute: 35,
+                second: 58,
+            }
+        );
+        assert_eq!(datetime.to_string(), "2024-02-29 19:35:58");
+        assert_eq!(p.parse_dos_datetime().unwrap().to_string(), "1980-01-01 00:00:00");
+        assert!(p.is_at_end());
+    }
+
+    #[test]
+    fn try_parsing_dos_datetime_out_of_range() {
+        let input: &[u8] = &[
+            0x00, 0x00, 0x5D, 0x4A, //< 2017-02-29 00:00:00
+            0x00, 0x00, 0x80, 0x01, //< 1980-12-00 00:00:00
+            0x00, 0xC0, 0x21, 0x00, //< 1980-01-01 24:00:00
+        ];
+
+        let mut p = BytesParser::from(input);
+        p.set_endian(ParsingEndian::LE);
+
+        assert_eq!(
+            p.parse_dos_datetime().unwrap_err(),
+            BytesParserError::TimestampOutOfRangeError("2017-02-29 00:00:00".to_string(), 0)
+        );
+        assert!(p.is_at_start());
+
+        for position in [4, 8] {
+            assert!(p.move_at(position).is_ok());
+            assert!(matches!(
+                p.parse_dos_datetime().unwrap_err(),
+                BytesParserError::TimestampOutOfRangeError(_, pos) if pos == position
+            ));
+        }
+    }
+}
@@ -0,0 +1,184 @@
+use crate::endianness::ParsingEndian;
+use crate::errors::BytesParserError;
+use crate::parser::BytesParser;
+use crate::scalar::Scalar;
+
+use std::iter::FusedIterator;
+use std::marker::PhantomData;
+use std::slice::ChunksExact;
+
+/// A zero-copy, lazy view over contiguous scalars of type `T`.
+///
+/// It wraps a slice of the original bytes array, and decodes the scalar values only when accessed,
+/// using the [`ParsingEndian`] that was in use when the view was created.
+/// This is useful to index into large tables, without having to materialize them into a [`Vec`].
+///
+/// See [`BytesParser::parse_scalar_view`].
+#[derive(Debug, Copy, Clone)]
+pub struct ScalarView<'a, T> {
+    bytes: &'a [u8],
+    endian: ParsingEndian,
+    scalar_type: PhantomData<T>,
+}
+
+impl<'a, T: Scalar> ScalarView<'a, T> {
+    /// Amount of scalars in the view.
+    pub fn len(&self) -> usize {
+        self.bytes.len() / T::SIZE
+    }
+
+    /// Returns [`true`] if the view contains no scalars.
+    pub fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+
+    /// Returns the [ParsingEndian] used to decode the scalars.
+    pub const fn endian(&self) -> ParsingEndian {
+        self.endian
+    }
+
+    /// Returns the underlying slice of bytes.
+    pub const fn as_bytes(&self) -> &'a [u8] {
+        self.bytes
+    }
+
+    /// Decode the scalar at `index`, or return [`None`] if `index` is out-of-bound.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The 0-based index of the scalar to decode.
+    pub fn get(&self, index: usize) -> Option<T> {
+        let start = index.checked_mul(T::SIZE)?;
+        let end = start.checked_add(T::SIZE)?;
+        let bytes = self.bytes.get(start..end)?;
+
+        Some(T::from_endian_bytes(bytes, self.endian))
+    }
+
+    /// Returns an iterator that decodes the scalars, in order.
+    pub fn iter(&self) -> ScalarViewIter<'a, T> {
+        ScalarViewIter {
+            chunks: self.bytes.chunks_exact(T::SIZE),
+            endian: self.endian,
+            scalar_type: PhantomData,
+        }
+    }
+}
+
+impl<'a, T: Scalar> IntoIterator for ScalarView<'a, T> {
+    type Item = T;
+    type IntoIter = ScalarViewIter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Iterator over the scalars of a [`ScalarView`].
+///
+/// It's created by [`ScalarView::iter`].
+#[derive(Debug, Clone)]
+pub struct ScalarViewIter<'a, T> {
+    chunks: ChunksExact<'a, u8>,
+    endian: ParsingEndian,
+    scalar_type: PhantomData<T>,
+}
+
+impl<T: Scalar> Iterator for ScalarViewIter<'_, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.chunks.next().map(|bytes| T::from_endian_bytes(bytes, self.endian))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.chunks.size_hint()
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.chunks.nth(n).map(|bytes| T::from_endian_bytes(bytes, self.endian))
+    }
+}
+
+impl<T: Scalar> DoubleEndedIterator for ScalarViewIter<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.chunks.next_back().map(|bytes| T::from_endian_bytes(bytes, self.endian))
+    }
+}
+
+impl<T: Scalar> ExactSizeIterator for ScalarViewIter<'_, T> {}
+
+impl<T: Scalar> FusedIterator for ScalarViewIter<'_, T> {}
+
+impl<'a> BytesParser<'a> {
+    /// Creates a [`ScalarView`] over `n` contiguous scalars of type `T`, and update the internal
+    /// cursor accordingly.
+    ///
+    /// Nothing is decoded at this stage: the scalars are decoded when accessed,
+    /// using the [`ParsingEndian`] currently in use (i.e. [`Self::endian`]).
+    /// It produces an error if [`BytesParser::parseable`] returns an amount inferior to
+    /// the amount of bytes occupied by `n` scalars of type `T`.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - Amount of scalars in the view.
+    pub fn parse_scalar_view<T: Scalar>(&mut self, n: usize) -> Result<ScalarView<'a, T>, BytesParserError> {
+        let bytes = self.parse_scalars_slice::<T>(n)?;
+
+        Ok(ScalarView {
+            bytes,
+            endian: self.endian(),
+            scalar_type: PhantomData,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{BytesParser, BytesParserError, ParsingEndian, ScalarView};
+
+    #[test]
+    fn parse_scalar_view() {
+        let input: &[u8] = &[
+            0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x20, 0x00, 0x00, 0x00, 0x30, //< 3 x u32
+            0xFF, //< trailing u8
+        ];
+
+        let mut p = BytesParser::from(input);
+
+        let view: ScalarView<u32> = p.parse_scalar_view(3).unwrap();
+        assert_eq!(p.parse_u8().unwrap(), 0xFF);
+        assert!(p.is_at_end());
+
+        assert_eq!(view.len(), 3);
+        assert!(!view.is_empty());
+        assert_eq!(view.get(0), Some(0x10));
+        assert_eq!(view.get(2), Some(0x30));
+        assert_eq!(view.get(3), None);
+        assert_eq!(view.get(usize::MAX), None);
+        assert_eq!(view.get(usize::MAX / 4), None);
+        assert_eq!(view.iter().collect::<Vec<_>>(), vec![0x10, 0x20, 0x30]);
+        assert_eq!(view.iter().next_back(), Some(0x30));
+        assert_eq!(view.into_iter().len(), 3);
+    }
+
+    #[test]
+    fn parse_scalar_view_keeps_endian() {
+        let input: &[u8] = &[0x01, 0x00, 0x02, 0x00];
+
+        let mut p = BytesParser::from(input);
+        p.set_endian(ParsingEndian::LE);
+
+        let view = p.parse_scalar_view::<i16>(2).unwrap();
+        p.set_endian(ParsingEndian::BE);
+
+        assert_eq!(view.endian(), ParsingEndian::LE);
+        assert_eq!(view.iter().sum::<i16>(), 3);
+
+        p.reset();
+        assert_eq!(
+            p.parse_scalar_view::<u64>(1).unwrap_err(),
+            BytesParserError::NotEnoughBytesForTypeError("[u64; 1]".to_string())
+        );
+    }
+}